@@ -0,0 +1,39 @@
+use crate::StaticResult;
+
+impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
+	/// Convert a [`StaticResult`] into an [`anyhow::Result`], converting the error into an [`anyhow::Error`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, anyhow::Error, true>::new_ok(42);
+	/// assert_eq!(42, result.into_anyhow().unwrap());
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticResult;
+	/// use std::fmt::{Display, Formatter};
+	///
+	/// #[derive(Debug)]
+	/// struct MyError;
+	///
+	/// impl Display for MyError {
+	/// 	fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+	/// 		formatter.write_str("my error")
+	/// 	}
+	/// }
+	///
+	/// impl std::error::Error for MyError {}
+	///
+	/// fn fails() -> anyhow::Result<i32> {
+	/// 	StaticResult::<i32, _, false>::new_err(MyError).into_anyhow()
+	/// }
+	/// assert_eq!("my error", fails().unwrap_err().to_string());
+	/// ```
+	pub fn into_anyhow(self) -> anyhow::Result<T>
+	where
+		E: Into<anyhow::Error>,
+	{
+		self.into_result().map_err(Into::into)
+	}
+}
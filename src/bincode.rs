@@ -0,0 +1,177 @@
+use crate::{StaticOption, StaticResult};
+use bincode::de::{BorrowDecode, BorrowDecoder, Decode, Decoder};
+use bincode::enc::{Encode, Encoder};
+use bincode::error::{AllowedEnumVariants, DecodeError, EncodeError};
+
+/// Uses the same representation as [`Option`], a `u8` presence tag followed by the value if present.
+///
+/// Decoding fails with an error if the presence tag doesn't match `IS_SOME`.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticOption;
+/// let config = bincode::config::standard();
+/// let mut bytes = [0u8; 8];
+/// let mut expected = [0u8; 8];
+///
+/// let length = bincode::encode_into_slice(StaticOption::some(42u32), &mut bytes, config).unwrap();
+/// let expected_length = bincode::encode_into_slice(Some(42u32), &mut expected, config).unwrap();
+/// assert_eq!(expected[..expected_length], bytes[..length]);
+/// let (decoded, decoded_length) = bincode::decode_from_slice::<StaticOption<u32, true>, _>(&bytes, config).unwrap();
+/// assert_eq!(StaticOption::some(42u32), decoded);
+/// assert_eq!(length, decoded_length);
+///
+/// let length = bincode::encode_into_slice(StaticOption::<u32, false>::none(), &mut bytes, config).unwrap();
+/// let expected_length = bincode::encode_into_slice(None::<u32>, &mut expected, config).unwrap();
+/// assert_eq!(expected[..expected_length], bytes[..length]);
+/// let (decoded, _) = bincode::decode_from_slice::<StaticOption<u32, false>, _>(&bytes, config).unwrap();
+/// assert_eq!(StaticOption::<u32, false>::none(), decoded);
+/// ```
+///
+/// ```
+/// # use static_option::StaticOption;
+/// let config = bincode::config::standard();
+/// let mut bytes = [0u8; 8];
+///
+/// bincode::encode_into_slice(StaticOption::some(42u32), &mut bytes, config).unwrap();
+/// assert!(bincode::decode_from_slice::<StaticOption<u32, false>, _>(&bytes, config).is_err());
+///
+/// bincode::encode_into_slice(StaticOption::<u32, false>::none(), &mut bytes, config).unwrap();
+/// assert!(bincode::decode_from_slice::<StaticOption<u32, true>, _>(&bytes, config).is_err());
+/// ```
+impl<T, const IS_SOME: bool> Encode for StaticOption<T, IS_SOME>
+where
+	T: Encode,
+{
+	fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+		u8::from(IS_SOME).encode(encoder)?;
+		if IS_SOME {
+			self.as_inner().encode(encoder)?;
+		}
+		Ok(())
+	}
+}
+
+impl<Context, T, const IS_SOME: bool> Decode<Context> for StaticOption<T, IS_SOME>
+where
+	T: Decode<Context>,
+{
+	fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+		decode_tag::<_, Self>(decoder, IS_SOME.into())?;
+		if IS_SOME {
+			Ok(StaticOption::new_some(T::decode(decoder)?))
+		} else {
+			Ok(StaticOption::new_none())
+		}
+	}
+}
+
+impl<'de, Context, T, const IS_SOME: bool> BorrowDecode<'de, Context> for StaticOption<T, IS_SOME>
+where
+	T: BorrowDecode<'de, Context>,
+{
+	fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+		decode_tag::<_, Self>(decoder, IS_SOME.into())?;
+		if IS_SOME {
+			Ok(StaticOption::new_some(T::borrow_decode(decoder)?))
+		} else {
+			Ok(StaticOption::new_none())
+		}
+	}
+}
+
+/// Uses a `u8` tag that is `0` for `ok` and `1` for `err`, followed by the `ok` value or the error respectively.
+/// With variable integer encoding (the default), this is the same representation as [`Result`].
+///
+/// Decoding fails with an error if the tag doesn't match `IS_OK`.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticResult;
+/// let config = bincode::config::standard();
+/// let mut bytes = [0u8; 8];
+/// let mut expected = [0u8; 8];
+///
+/// let result = StaticResult::<u32, i8, true>::new_ok(42);
+/// let length = bincode::encode_into_slice(&result, &mut bytes, config).unwrap();
+/// let expected_length = bincode::encode_into_slice(Ok::<u32, i8>(42), &mut expected, config).unwrap();
+/// assert_eq!(expected[..expected_length], bytes[..length]);
+/// let (decoded, _) = bincode::decode_from_slice::<StaticResult<u32, i8, true>, _>(&bytes, config).unwrap();
+/// assert_eq!(result, decoded);
+///
+/// let result = StaticResult::<u32, i8, false>::new_err(-1);
+/// let length = bincode::encode_into_slice(&result, &mut bytes, config).unwrap();
+/// let expected_length = bincode::encode_into_slice(Err::<u32, i8>(-1), &mut expected, config).unwrap();
+/// assert_eq!(expected[..expected_length], bytes[..length]);
+/// let (decoded, _) = bincode::decode_from_slice::<StaticResult<u32, i8, false>, _>(&bytes, config).unwrap();
+/// assert_eq!(result, decoded);
+/// ```
+///
+/// ```
+/// # use static_option::StaticResult;
+/// let config = bincode::config::standard();
+/// let mut bytes = [0u8; 8];
+///
+/// bincode::encode_into_slice(StaticResult::<u32, u32, true>::new_ok(42), &mut bytes, config).unwrap();
+/// assert!(bincode::decode_from_slice::<StaticResult<u32, u32, false>, _>(&bytes, config).is_err());
+/// ```
+impl<T, E, const IS_OK: bool> Encode for StaticResult<T, E, IS_OK>
+where
+	T: Encode,
+	E: Encode,
+{
+	fn encode<Enc: Encoder>(&self, encoder: &mut Enc) -> Result<(), EncodeError> {
+		u8::from(!IS_OK).encode(encoder)?;
+		match self.as_result() {
+			Ok(ok) => ok.encode(encoder),
+			Err(error) => error.encode(encoder),
+		}
+	}
+}
+
+impl<Context, T, E, const IS_OK: bool> Decode<Context> for StaticResult<T, E, IS_OK>
+where
+	T: Decode<Context>,
+	E: Decode<Context>,
+{
+	fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+		decode_tag::<_, Self>(decoder, (!IS_OK).into())?;
+		if IS_OK {
+			Ok(StaticResult::create_ok(T::decode(decoder)?))
+		} else {
+			Ok(StaticResult::create_err(E::decode(decoder)?))
+		}
+	}
+}
+
+impl<'de, Context, T, E, const IS_OK: bool> BorrowDecode<'de, Context> for StaticResult<T, E, IS_OK>
+where
+	T: BorrowDecode<'de, Context>,
+	E: BorrowDecode<'de, Context>,
+{
+	fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+		decode_tag::<_, Self>(decoder, (!IS_OK).into())?;
+		if IS_OK {
+			Ok(StaticResult::create_ok(T::borrow_decode(decoder)?))
+		} else {
+			Ok(StaticResult::create_err(E::borrow_decode(decoder)?))
+		}
+	}
+}
+
+fn decode_tag<D: Decoder, Type>(decoder: &mut D, expected: u8) -> Result<(), DecodeError> {
+	let found = u8::decode(decoder)?;
+	if found == expected {
+		return Ok(());
+	}
+
+	Err(DecodeError::UnexpectedVariant {
+		type_name: core::any::type_name::<Type>(),
+		allowed: if expected == 0 {
+			&AllowedEnumVariants::Allowed(&[0])
+		} else {
+			&AllowedEnumVariants::Allowed(&[1])
+		},
+		found: found.into(),
+	})
+}
@@ -0,0 +1,26 @@
+use crate::StaticOption;
+use bytemuck::{Pod, Zeroable};
+
+// SAFETY: A `StaticOption<T, false>` never contains a value, so any bit pattern, including all zeroes, is valid.
+unsafe impl<T> Zeroable for StaticOption<T, false> {}
+
+// SAFETY: `StaticOption` is `#[repr(C)]`, so a `StaticOption<T, true>` has the same layout as `T`
+// and an all zero `StaticOption<T, true>` contains an all zero `T`, which is valid because `T: Zeroable`.
+unsafe impl<T> Zeroable for StaticOption<T, true> where T: Zeroable {}
+
+/// # Example
+/// ```
+/// # use static_option::StaticOption;
+/// let options = [StaticOption::some(1.0f32), StaticOption::some(2.0f32)];
+/// let bytes: &[u8] = bytemuck::cast_slice(&options);
+/// assert_eq!(8, bytes.len());
+///
+/// let floats: &[f32] = bytemuck::cast_slice(&options);
+/// assert_eq!(&[1.0, 2.0], floats);
+///
+/// let roundtrip: &[StaticOption<f32, true>] = bytemuck::cast_slice(bytes);
+/// assert_eq!(&options, roundtrip);
+/// ```
+// SAFETY: `StaticOption` is `#[repr(C)]`, so a `StaticOption<T, true>` has the same layout as `T`
+// and always contains a `T`, so it has no padding or uninitialized bytes and every bit pattern is valid.
+unsafe impl<T> Pod for StaticOption<T, true> where T: Pod {}
@@ -1,3 +1,5 @@
+use core::iter::FusedIterator;
+
 pub struct Iter<T> {
 	value: Option<T>,
 }
@@ -14,4 +16,23 @@ impl<T> Iterator for Iter<T> {
 	fn next(&mut self) -> Option<Self::Item> {
 		self.value.take()
 	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let length = self.len();
+		(length, Some(length))
+	}
 }
+
+impl<T> DoubleEndedIterator for Iter<T> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.value.take()
+	}
+}
+
+impl<T> ExactSizeIterator for Iter<T> {
+	fn len(&self) -> usize {
+		usize::from(self.value.is_some())
+	}
+}
+
+impl<T> FusedIterator for Iter<T> {}
@@ -1,10 +1,50 @@
 #![no_std]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
 #![allow(clippy::tabs_in_doc_comments)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(any(
+	feature = "alloc",
+	feature = "miniserde",
+	feature = "nanoserde",
+	feature = "schemars"
+))]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "anyhow")]
+mod anyhow;
+#[cfg(feature = "bincode")]
+mod bincode;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
 mod iterator;
+#[cfg(feature = "minicbor")]
+mod minicbor;
+#[cfg(feature = "miniserde")]
+mod miniserde;
+#[cfg(feature = "nanoserde")]
+mod nanoserde;
 mod option;
+mod option_dyn;
 mod result;
+#[cfg(feature = "scale-info")]
+mod scale_info;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "speedy")]
+mod speedy;
+mod tuple;
+#[cfg(feature = "valuable")]
+mod valuable;
+#[cfg(feature = "zerocopy")]
+mod zerocopy;
 pub use iterator::Iter;
-pub use option::StaticOption;
-pub use result::StaticResult;
+pub use option::{Absent, Present, StaticOption};
+pub use option_dyn::StaticOptionDyn;
+pub use result::{ErrResult, OkResult, StaticResult};
+pub use tuple::{all, any, AllOptions, AnyOptions};
@@ -0,0 +1,166 @@
+use crate::{StaticOption, StaticResult};
+use minicbor::data::Type;
+use minicbor::decode::{self, Decode, Decoder};
+use minicbor::encode::{self, Encode, Encoder, Write};
+
+/// Uses the same representation as [`Option`], the value itself if present and `null` otherwise.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticOption;
+/// use minicbor::encode::write::Cursor;
+///
+/// let mut buffer = [0u8; 8];
+/// let mut cursor = Cursor::new(&mut buffer[..]);
+/// minicbor::encode(StaticOption::some(42u32), &mut cursor).unwrap();
+/// let length = cursor.position();
+///
+/// let mut expected = [0u8; 8];
+/// minicbor::encode(Some(42u32), &mut expected[..]).unwrap();
+/// assert_eq!(expected, buffer);
+/// assert_eq!(StaticOption::some(42u32), minicbor::decode(&buffer[..length]).unwrap());
+/// ```
+///
+/// ```
+/// # use static_option::StaticOption;
+/// use minicbor::encode::write::Cursor;
+///
+/// let mut buffer = [0u8; 8];
+/// let mut cursor = Cursor::new(&mut buffer[..]);
+/// minicbor::encode(StaticOption::<u32, false>::none(), &mut cursor).unwrap();
+/// let length = cursor.position();
+///
+/// let mut expected = [0u8; 8];
+/// minicbor::encode(None::<u32>, &mut expected[..]).unwrap();
+/// assert_eq!(expected, buffer);
+/// assert_eq!(StaticOption::<u32, false>::none(), minicbor::decode(&buffer[..length]).unwrap());
+/// ```
+impl<C, T, const IS_SOME: bool> Encode<C> for StaticOption<T, IS_SOME>
+where
+	T: Encode<C>,
+{
+	fn encode<W: Write>(&self, encoder: &mut Encoder<W>, context: &mut C) -> Result<(), encode::Error<W::Error>> {
+		match self.as_option() {
+			Some(value) => value.encode(encoder, context),
+			None => encoder.null()?.ok(),
+		}
+	}
+
+	fn is_nil(&self) -> bool {
+		!IS_SOME
+	}
+}
+
+/// Decoding fails if a value is present in a [`StaticOption<T, false>`] or missing in a [`StaticOption<T, true>`].
+///
+/// # Example
+/// ```
+/// # use static_option::StaticOption;
+/// let mut buffer = [0u8; 8];
+/// minicbor::encode(Some(42u32), &mut buffer[..]).unwrap();
+/// assert!(minicbor::decode::<StaticOption<u32, false>>(&buffer).is_err());
+///
+/// let mut buffer = [0u8; 8];
+/// minicbor::encode(None::<u32>, &mut buffer[..]).unwrap();
+/// assert!(minicbor::decode::<StaticOption<u32, true>>(&buffer).is_err());
+/// ```
+impl<'b, C, T, const IS_SOME: bool> Decode<'b, C> for StaticOption<T, IS_SOME>
+where
+	T: Decode<'b, C>,
+{
+	fn decode(decoder: &mut Decoder<'b>, context: &mut C) -> Result<Self, decode::Error> {
+		if IS_SOME {
+			return T::decode(decoder, context).map(StaticOption::new_some);
+		}
+
+		let position = decoder.position();
+		if decoder.datatype()? != Type::Null {
+			return Err(decode::Error::message("expected `null` for a `StaticOption::none`").at(position));
+		}
+		decoder.skip()?;
+		Ok(StaticOption::new_none())
+	}
+
+	fn nil() -> Option<Self> {
+		if IS_SOME {
+			T::nil().map(StaticOption::new_some)
+		} else {
+			Some(StaticOption::new_none())
+		}
+	}
+}
+
+/// Uses the same representation as [`Result`], a 2 element array with `0` followed by the `ok` value
+/// or `1` followed by the error.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticResult;
+/// let mut buffer = [0u8; 8];
+/// minicbor::encode(StaticResult::<u32, u32, true>::new_ok(42), &mut buffer[..]).unwrap();
+///
+/// let mut expected = [0u8; 8];
+/// minicbor::encode(Ok::<u32, u32>(42), &mut expected[..]).unwrap();
+/// assert_eq!(expected, buffer);
+/// assert_eq!(StaticResult::<u32, u32, true>::new_ok(42), minicbor::decode(&buffer).unwrap());
+/// ```
+///
+/// ```
+/// # use static_option::StaticResult;
+/// let mut buffer = [0u8; 8];
+/// minicbor::encode(StaticResult::<u32, u32, false>::new_err(42), &mut buffer[..]).unwrap();
+///
+/// let mut expected = [0u8; 8];
+/// minicbor::encode(Err::<u32, u32>(42), &mut expected[..]).unwrap();
+/// assert_eq!(expected, buffer);
+/// assert_eq!(StaticResult::<u32, u32, false>::new_err(42), minicbor::decode(&buffer).unwrap());
+/// ```
+impl<C, T, E, const IS_OK: bool> Encode<C> for StaticResult<T, E, IS_OK>
+where
+	T: Encode<C>,
+	E: Encode<C>,
+{
+	fn encode<W: Write>(&self, encoder: &mut Encoder<W>, context: &mut C) -> Result<(), encode::Error<W::Error>> {
+		encoder.array(2)?;
+		match self.as_result() {
+			Ok(ok) => encoder.u32(0)?.encode_with(ok, context)?.ok(),
+			Err(error) => encoder.u32(1)?.encode_with(error, context)?.ok(),
+		}
+	}
+}
+
+/// Decoding fails if an `ok` value is found for a [`StaticResult<T, E, false>`]
+/// or an error for a [`StaticResult<T, E, true>`].
+///
+/// # Example
+/// ```
+/// # use static_option::StaticResult;
+/// let mut buffer = [0u8; 8];
+/// minicbor::encode(Ok::<u32, u32>(42), &mut buffer[..]).unwrap();
+/// assert!(minicbor::decode::<StaticResult<u32, u32, false>>(&buffer).is_err());
+///
+/// let mut buffer = [0u8; 8];
+/// minicbor::encode(Err::<u32, u32>(42), &mut buffer[..]).unwrap();
+/// assert!(minicbor::decode::<StaticResult<u32, u32, true>>(&buffer).is_err());
+/// ```
+impl<'b, C, T, E, const IS_OK: bool> Decode<'b, C> for StaticResult<T, E, IS_OK>
+where
+	T: Decode<'b, C>,
+	E: Decode<'b, C>,
+{
+	fn decode(decoder: &mut Decoder<'b>, context: &mut C) -> Result<Self, decode::Error> {
+		let position = decoder.position();
+		if decoder.array()? != Some(2) {
+			return Err(decode::Error::message("expected enum (2-element array)").at(position));
+		}
+
+		let position = decoder.position();
+		match (decoder.i64()?, IS_OK) {
+			(0, true) => T::decode(decoder, context).map(StaticResult::create_ok),
+			(1, false) => E::decode(decoder, context).map(StaticResult::create_err),
+			(0, false) => Err(decode::Error::message("expected an error for a `StaticResult::err`").at(position)),
+			(1, true) => Err(decode::Error::message("expected an `ok` value for a `StaticResult::ok`").at(position)),
+			(variant, _) => Err(decode::Error::unknown_variant(variant).at(position)),
+		}
+	}
+}
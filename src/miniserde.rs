@@ -0,0 +1,338 @@
+use crate::{StaticOption, StaticResult};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use miniserde::de::{self, Deserialize, Visitor};
+use miniserde::ser::{self, Fragment, Serialize};
+use miniserde::{make_place, Error, Result};
+
+/// Uses the same JSON representation as [`Option`], the value itself if present and `null` otherwise.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticOption;
+/// use miniserde::json;
+/// use std::collections::BTreeMap;
+///
+/// let json = json::to_string(&StaticOption::some(42));
+/// assert_eq!("42", json);
+/// assert_eq!(StaticOption::some(42), json::from_str(&json).unwrap());
+///
+/// let json = json::to_string(&StaticOption::<u32, false>::none());
+/// assert_eq!("null", json);
+/// assert_eq!(StaticOption::<u32, false>::none(), json::from_str(&json).unwrap());
+///
+/// let json = json::to_string(&StaticOption::some(vec![1, 2, 3]));
+/// assert_eq!("[1,2,3]", json);
+/// assert_eq!(StaticOption::some(vec![1, 2, 3]), json::from_str(&json).unwrap());
+///
+/// let map = BTreeMap::from([(String::from("answer"), 42)]);
+/// let json = json::to_string(&StaticOption::some(map.clone()));
+/// assert_eq!(r#"{"answer":42}"#, json);
+/// assert_eq!(StaticOption::some(map), json::from_str(&json).unwrap());
+/// ```
+impl<T, const IS_SOME: bool> Serialize for StaticOption<T, IS_SOME>
+where
+	T: Serialize,
+{
+	fn begin(&self) -> Fragment<'_> {
+		match self.as_option() {
+			Some(value) => value.begin(),
+			None => Fragment::Null,
+		}
+	}
+}
+
+/// Deserializing fails if a value is present in a [`StaticOption<T, false>`] or missing in a [`StaticOption<T, true>`].
+///
+/// # Example
+/// ```
+/// # use static_option::StaticOption;
+/// use miniserde::json;
+///
+/// assert!(json::from_str::<StaticOption<u32, false>>("42").is_err());
+/// assert!(json::from_str::<StaticOption<u32, true>>("null").is_err());
+/// assert!(json::from_str::<StaticOption<Vec<u32>, true>>(r#"[1, "2"]"#).is_err());
+/// assert!(json::from_str::<StaticOption<Vec<u32>, false>>("[1, 2]").is_err());
+/// ```
+impl<T, const IS_SOME: bool> Deserialize for StaticOption<T, IS_SOME>
+where
+	T: Deserialize,
+{
+	fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+		make_place!(Place);
+
+		impl<T, const IS_SOME: bool> Place<StaticOption<T, IS_SOME>>
+		where
+			T: Deserialize,
+		{
+			fn visit_some(&mut self, visit: impl FnOnce(&mut dyn Visitor) -> Result<()>) -> Result<()> {
+				if !IS_SOME {
+					return Err(Error);
+				}
+
+				let mut value = None;
+				visit(T::begin(&mut value))?;
+				self.out = Some(StaticOption::new_some(value.ok_or(Error)?));
+				Ok(())
+			}
+		}
+
+		impl<T, const IS_SOME: bool> Visitor for Place<StaticOption<T, IS_SOME>>
+		where
+			T: Deserialize,
+		{
+			fn null(&mut self) -> Result<()> {
+				if IS_SOME {
+					self.visit_some(|visitor| visitor.null())
+				} else {
+					self.out = Some(StaticOption::new_none());
+					Ok(())
+				}
+			}
+
+			fn boolean(&mut self, b: bool) -> Result<()> {
+				self.visit_some(|visitor| visitor.boolean(b))
+			}
+
+			fn string(&mut self, s: &str) -> Result<()> {
+				self.visit_some(|visitor| visitor.string(s))
+			}
+
+			fn negative(&mut self, n: i64) -> Result<()> {
+				self.visit_some(|visitor| visitor.negative(n))
+			}
+
+			fn nonnegative(&mut self, n: u64) -> Result<()> {
+				self.visit_some(|visitor| visitor.nonnegative(n))
+			}
+
+			fn float(&mut self, n: f64) -> Result<()> {
+				self.visit_some(|visitor| visitor.float(n))
+			}
+
+			fn seq(&mut self) -> Result<Box<dyn de::Seq + '_>> {
+				if !IS_SOME {
+					return Err(Error);
+				}
+
+				let value = Box::into_raw(Box::new(None));
+				// SAFETY: `value` was just allocated and is only freed by `SomeSeq` after the `Seq` borrowing it
+				// has been dropped, or right here if no `Seq` was created.
+				let seq = match unsafe { T::begin(&mut *value) }.seq() {
+					Ok(seq) => seq,
+					Err(error) => {
+						drop(unsafe { Box::from_raw(value) });
+						return Err(error);
+					}
+				};
+				Ok(Box::new(SomeSeq {
+					out: &mut self.out,
+					value,
+					seq: Some(seq),
+				}))
+			}
+
+			fn map(&mut self) -> Result<Box<dyn de::Map + '_>> {
+				if !IS_SOME {
+					return Err(Error);
+				}
+
+				let value = Box::into_raw(Box::new(None));
+				// SAFETY: `value` was just allocated and is only freed by `SomeMap` after the `Map` borrowing it
+				// has been dropped, or right here if no `Map` was created.
+				let map = match unsafe { T::begin(&mut *value) }.map() {
+					Ok(map) => map,
+					Err(error) => {
+						drop(unsafe { Box::from_raw(value) });
+						return Err(error);
+					}
+				};
+				Ok(Box::new(SomeMap {
+					out: &mut self.out,
+					value,
+					map: Some(map),
+				}))
+			}
+		}
+
+		Place::new(out)
+	}
+}
+
+// The inner `Seq` borrows from `value`, so `value` is kept on the heap and is only accessed or freed after `seq`
+// has been dropped.
+struct SomeSeq<'a, T, const IS_SOME: bool> {
+	out: &'a mut Option<StaticOption<T, IS_SOME>>,
+	value: *mut Option<T>,
+	seq: Option<Box<dyn de::Seq + 'a>>,
+}
+
+impl<T, const IS_SOME: bool> Drop for SomeSeq<'_, T, IS_SOME> {
+	fn drop(&mut self) {
+		self.seq = None;
+		// SAFETY: `seq` was dropped, so nothing borrows from `value` anymore when it is freed.
+		drop(unsafe { Box::from_raw(self.value) });
+	}
+}
+
+impl<T, const IS_SOME: bool> de::Seq for SomeSeq<'_, T, IS_SOME> {
+	fn element(&mut self) -> Result<&mut dyn Visitor> {
+		self.seq.as_mut().ok_or(Error)?.element()
+	}
+
+	fn finish(&mut self) -> Result<()> {
+		let mut seq = self.seq.take().ok_or(Error)?;
+		seq.finish()?;
+		drop(seq);
+		// SAFETY: `value` stays allocated until `self` is dropped and `seq` was dropped, so nothing borrows from it.
+		let value = unsafe { (*self.value).take() }.ok_or(Error)?;
+		*self.out = Some(StaticOption::new_some(value));
+		Ok(())
+	}
+}
+
+// The inner `Map` borrows from `value`, so `value` is kept on the heap and is only accessed or freed after `map`
+// has been dropped.
+struct SomeMap<'a, T, const IS_SOME: bool> {
+	out: &'a mut Option<StaticOption<T, IS_SOME>>,
+	value: *mut Option<T>,
+	map: Option<Box<dyn de::Map + 'a>>,
+}
+
+impl<T, const IS_SOME: bool> Drop for SomeMap<'_, T, IS_SOME> {
+	fn drop(&mut self) {
+		self.map = None;
+		// SAFETY: `map` was dropped, so nothing borrows from `value` anymore when it is freed.
+		drop(unsafe { Box::from_raw(self.value) });
+	}
+}
+
+impl<T, const IS_SOME: bool> de::Map for SomeMap<'_, T, IS_SOME> {
+	fn key(&mut self, k: &str) -> Result<&mut dyn Visitor> {
+		self.map.as_mut().ok_or(Error)?.key(k)
+	}
+
+	fn finish(&mut self) -> Result<()> {
+		let mut map = self.map.take().ok_or(Error)?;
+		map.finish()?;
+		drop(map);
+		// SAFETY: `value` stays allocated until `self` is dropped and `map` was dropped, so nothing borrows from it.
+		let value = unsafe { (*self.value).take() }.ok_or(Error)?;
+		*self.out = Some(StaticOption::new_some(value));
+		Ok(())
+	}
+}
+
+/// Uses the same JSON representation as [`Result`] in `serde`, `{"Ok": ok}` or `{"Err": error}`.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticResult;
+/// use miniserde::json;
+///
+/// let result = StaticResult::<u32, String, true>::new_ok(42);
+/// let json = json::to_string(&result);
+/// assert_eq!(r#"{"Ok":42}"#, json);
+/// assert_eq!(result, json::from_str(&json).unwrap());
+///
+/// let result = StaticResult::<u32, String, false>::new_err("error".into());
+/// let json = json::to_string(&result);
+/// assert_eq!(r#"{"Err":"error"}"#, json);
+/// assert_eq!(result, json::from_str(&json).unwrap());
+/// ```
+impl<T, E, const IS_OK: bool> Serialize for StaticResult<T, E, IS_OK>
+where
+	T: Serialize,
+	E: Serialize,
+{
+	fn begin(&self) -> Fragment<'_> {
+		Fragment::Map(Box::new(ResultStream {
+			result: Some(self.as_result()),
+		}))
+	}
+}
+
+struct ResultStream<'a, T, E> {
+	result: Option<core::result::Result<&'a T, &'a E>>,
+}
+
+impl<T, E> ser::Map for ResultStream<'_, T, E>
+where
+	T: Serialize,
+	E: Serialize,
+{
+	fn next(&mut self) -> Option<(Cow<'_, str>, &dyn Serialize)> {
+		match self.result.take()? {
+			Ok(ok) => Some((Cow::Borrowed("Ok"), ok)),
+			Err(error) => Some((Cow::Borrowed("Err"), error)),
+		}
+	}
+}
+
+/// Deserializing fails if an `ok` value is found for a [`StaticResult<T, E, false>`]
+/// or an error for a [`StaticResult<T, E, true>`].
+///
+/// # Example
+/// ```
+/// # use static_option::StaticResult;
+/// use miniserde::json;
+///
+/// assert!(json::from_str::<StaticResult<u32, u32, false>>(r#"{"Ok":42}"#).is_err());
+/// assert!(json::from_str::<StaticResult<u32, u32, true>>(r#"{"Err":42}"#).is_err());
+/// assert!(json::from_str::<StaticResult<u32, u32, true>>("{}").is_err());
+/// ```
+impl<T, E, const IS_OK: bool> Deserialize for StaticResult<T, E, IS_OK>
+where
+	T: Deserialize,
+	E: Deserialize,
+{
+	fn begin(out: &mut Option<Self>) -> &mut dyn Visitor {
+		make_place!(Place);
+
+		impl<T, E, const IS_OK: bool> Visitor for Place<StaticResult<T, E, IS_OK>>
+		where
+			T: Deserialize,
+			E: Deserialize,
+		{
+			fn map(&mut self) -> Result<Box<dyn de::Map + '_>> {
+				Ok(Box::new(ResultBuilder {
+					out: &mut self.out,
+					ok: None,
+					error: None,
+				}))
+			}
+		}
+
+		Place::new(out)
+	}
+}
+
+struct ResultBuilder<'a, T, E, const IS_OK: bool> {
+	out: &'a mut Option<StaticResult<T, E, IS_OK>>,
+	ok: Option<T>,
+	error: Option<E>,
+}
+
+impl<T, E, const IS_OK: bool> de::Map for ResultBuilder<'_, T, E, IS_OK>
+where
+	T: Deserialize,
+	E: Deserialize,
+{
+	fn key(&mut self, k: &str) -> Result<&mut dyn Visitor> {
+		match k {
+			"Ok" if IS_OK && self.ok.is_none() => Ok(T::begin(&mut self.ok)),
+			"Err" if !IS_OK && self.error.is_none() => Ok(E::begin(&mut self.error)),
+			_ => Err(Error),
+		}
+	}
+
+	fn finish(&mut self) -> Result<()> {
+		let result = if IS_OK {
+			StaticResult::create_ok(self.ok.take().ok_or(Error)?)
+		} else {
+			StaticResult::create_err(self.error.take().ok_or(Error)?)
+		};
+		*self.out = Some(result);
+		Ok(())
+	}
+}
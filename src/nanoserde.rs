@@ -0,0 +1,129 @@
+use crate::{StaticOption, StaticResult};
+use alloc::vec::Vec;
+use nanoserde::{DeBin, DeBinErr, SerBin};
+
+/// Uses the same representation as [`Option`], a `u8` presence flag followed by the value if present.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticOption;
+/// use nanoserde::{DeBin, SerBin};
+///
+/// let bytes = StaticOption::some(42u32).serialize_bin();
+/// assert_eq!(Some(42u32).serialize_bin(), bytes);
+/// assert_eq!(StaticOption::some(42u32), StaticOption::deserialize_bin(&bytes).unwrap());
+///
+/// let bytes = StaticOption::<u32, false>::none().serialize_bin();
+/// assert_eq!(None::<u32>.serialize_bin(), bytes);
+/// assert_eq!(StaticOption::<u32, false>::none(), StaticOption::deserialize_bin(&bytes).unwrap());
+/// ```
+impl<T, const IS_SOME: bool> SerBin for StaticOption<T, IS_SOME>
+where
+	T: SerBin,
+{
+	fn ser_bin(&self, output: &mut Vec<u8>) {
+		output.push(IS_SOME.into());
+		if IS_SOME {
+			self.as_inner().ser_bin(output);
+		}
+	}
+}
+
+/// Deserializing fails if the presence flag doesn't match `IS_SOME`. Since [`DeBinErr`] can only describe
+/// length errors, a mismatch is reported at the offset of the flag, expecting `IS_SOME as usize` values
+/// and finding the number of values that are actually present.
+///
+/// # Example
+/// ```
+/// # use static_option::StaticOption;
+/// use nanoserde::{DeBin, SerBin};
+///
+/// let bytes = StaticOption::some(42u32).serialize_bin();
+/// assert!(StaticOption::<u32, false>::deserialize_bin(&bytes).is_err());
+///
+/// let bytes = StaticOption::<u32, false>::none().serialize_bin();
+/// assert!(StaticOption::<u32, true>::deserialize_bin(&bytes).is_err());
+/// ```
+impl<T, const IS_SOME: bool> DeBin for StaticOption<T, IS_SOME>
+where
+	T: DeBin,
+{
+	fn de_bin(offset: &mut usize, bytes: &[u8]) -> Result<Self, DeBinErr> {
+		let is_some = read_flag(offset, bytes, IS_SOME)?;
+		if is_some {
+			Ok(StaticOption::new_some(T::de_bin(offset, bytes)?))
+		} else {
+			Ok(StaticOption::new_none())
+		}
+	}
+}
+
+/// Uses a `u8` flag that is `1` for `ok` and `0` for `err`, followed by the `ok` value or the error respectively.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticResult;
+/// use nanoserde::{DeBin, SerBin};
+///
+/// let result = StaticResult::<u32, String, true>::new_ok(42);
+/// let bytes = result.serialize_bin();
+/// assert_eq!(result, StaticResult::deserialize_bin(&bytes).unwrap());
+///
+/// let result = StaticResult::<u32, String, false>::new_err("error".into());
+/// let bytes = result.serialize_bin();
+/// assert_eq!(result, StaticResult::deserialize_bin(&bytes).unwrap());
+/// ```
+impl<T, E, const IS_OK: bool> SerBin for StaticResult<T, E, IS_OK>
+where
+	T: SerBin,
+	E: SerBin,
+{
+	fn ser_bin(&self, output: &mut Vec<u8>) {
+		output.push(IS_OK.into());
+		match self.as_result() {
+			Ok(ok) => ok.ser_bin(output),
+			Err(error) => error.ser_bin(output),
+		}
+	}
+}
+
+/// Deserializing fails if the flag doesn't match `IS_OK`, reported the same way as for [`StaticOption`].
+///
+/// # Example
+/// ```
+/// # use static_option::StaticResult;
+/// use nanoserde::{DeBin, SerBin};
+///
+/// let bytes = StaticResult::<u32, u32, true>::new_ok(42).serialize_bin();
+/// assert!(StaticResult::<u32, u32, false>::deserialize_bin(&bytes).is_err());
+///
+/// let bytes = StaticResult::<u32, u32, false>::new_err(42).serialize_bin();
+/// assert!(StaticResult::<u32, u32, true>::deserialize_bin(&bytes).is_err());
+/// ```
+impl<T, E, const IS_OK: bool> DeBin for StaticResult<T, E, IS_OK>
+where
+	T: DeBin,
+	E: DeBin,
+{
+	fn de_bin(offset: &mut usize, bytes: &[u8]) -> Result<Self, DeBinErr> {
+		let is_ok = read_flag(offset, bytes, IS_OK)?;
+		if is_ok {
+			Ok(StaticResult::create_ok(T::de_bin(offset, bytes)?))
+		} else {
+			Ok(StaticResult::create_err(E::de_bin(offset, bytes)?))
+		}
+	}
+}
+
+fn read_flag(offset: &mut usize, bytes: &[u8], expected: bool) -> Result<bool, DeBinErr> {
+	let flag = match bytes.get(*offset) {
+		Some(&flag) => flag != 0,
+		None => return Err(DeBinErr::new(*offset, 1, bytes.len())),
+	};
+	if flag != expected {
+		return Err(DeBinErr::new(*offset, expected.into(), flag.into()));
+	}
+
+	*offset += 1;
+	Ok(flag)
+}
@@ -1,20 +1,85 @@
 use crate::iterator::Iter;
 use crate::StaticResult;
-use core::any::type_name;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::cmp::Ordering;
 use core::fmt::{Debug, Formatter};
+use core::future::Future;
 use core::hash::{Hash, Hasher};
-use core::mem::{swap, ManuallyDrop};
+use core::iter::Sum;
+use core::mem::{forget, swap, ManuallyDrop};
 use core::ops::{Deref, DerefMut};
 use core::pin::Pin;
-
+use core::task::{Context, Poll};
+
+/// An [`Option`] where the presence of a value is tracked by the `IS_SOME` const generic parameter.
+///
+/// # Layout
+/// [`StaticOption<T, true>`] and [`StaticOption<T, false>`] have the same size and alignment as `T` and the value of a
+/// [`StaticOption<T, true>`] is stored at offset 0. This is guaranteed by `#[repr(C)]` and can be relied on, e.g. for
+/// FFI.
+///
+/// ```
+/// # use static_option::StaticOption;
+/// use core::mem::{align_of, size_of};
+///
+/// const fn same_layout<T>() -> bool {
+/// 	size_of::<StaticOption<T, true>>() == size_of::<T>()
+/// 		&& align_of::<StaticOption<T, true>>() == align_of::<T>()
+/// 		&& size_of::<StaticOption<T, false>>() == size_of::<T>()
+/// 		&& align_of::<StaticOption<T, false>>() == align_of::<T>()
+/// }
+///
+/// const _: () = assert!(same_layout::<u64>());
+/// const _: () = assert!(same_layout::<[u8; 3]>());
+/// const _: () = assert!(same_layout::<(u8, u32)>());
+/// const _: () = assert!(same_layout::<()>());
+/// ```
 // A union is used instead of `MaybeUninit` because `assume_init` isn't a const fn in Rust 1.56, but union fields *can* be accessed inside a const fn.
 #[must_use = "Call `.drop()` if you don't use the StaticOption, otherwise it's contents never get dropped."]
+// `#[repr(C)]` guarantees that the value is stored at offset 0, giving `StaticOption<T, true>` the same layout as `T`.
+#[repr(C)]
+#[cfg_attr(
+	feature = "zerocopy",
+	derive(zerocopy::FromBytes, zerocopy::Immutable, zerocopy::KnownLayout)
+)]
 pub union StaticOption<T, const IS_SOME: bool> {
 	some: ManuallyDrop<T>,
 	none: (),
 }
 
+/// A [`StaticOption`] that always contains a value, shorthand for `StaticOption<T, true>`.
+///
+/// # Examples
+/// ```
+/// # use static_option::{Present, StaticOption};
+/// fn double(value: Present<i32>) -> Present<i32> {
+/// 	value.map(|value| value * 2)
+/// }
+///
+/// assert_eq!(StaticOption::some(84), double(Present::some(42)));
+/// let value: Option<i32> = double(StaticOption::some(21)).into();
+/// assert_eq!(Some(42), value);
+/// ```
+pub type Present<T> = StaticOption<T, true>;
+
+/// A [`StaticOption`] that never contains a value, shorthand for `StaticOption<T, false>`.
+///
+/// # Examples
+/// ```
+/// # use static_option::{Absent, StaticOption};
+/// fn nothing() -> Absent<i32> {
+/// 	Absent::none()
+/// }
+///
+/// assert_eq!(StaticOption::<i32, false>::none(), nothing());
+/// let value: Option<i32> = nothing().into();
+/// assert_eq!(None, value);
+/// ```
+pub type Absent<T> = StaticOption<T, false>;
+
 impl<T> StaticOption<T, true> {
 	/// Create a [`StaticOption<T, true>`] with a value inside. The `true` type parameter statically tracks
 	/// the fact that a value is inside.
@@ -22,6 +87,25 @@ impl<T> StaticOption<T, true> {
 		StaticOption::new_some(value)
 	}
 
+	/// Convert an [`Option`] that is known to contain a value into a [`StaticOption<T, true>`].
+	///
+	/// # Panics
+	/// Panics with `message` if `option` is [`None`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// assert_eq!(StaticOption::some(42), StaticOption::expect_some(Some(42), "no value"));
+	/// ```
+	///
+	/// ```should_panic
+	/// # use static_option::StaticOption;
+	/// StaticOption::<i32, true>::expect_some(None, "no value").drop();
+	/// ```
+	pub fn expect_some(option: Option<T>, message: &str) -> Self {
+		StaticOption::new_some(option.expect(message))
+	}
+
 	/// Take out the value from a [`StaticOption<T, true>`]. This is possible because the `true` statically guarantees
 	/// that there is a value inside.
 	///
@@ -65,6 +149,21 @@ impl<T> StaticOption<T, true> {
 		self.as_inner_mut()
 	}
 
+	/// Convert a [`StaticOption<T, true>`] into a [`StaticResult<T, E, true>`]. In contrast to
+	/// [`StaticOption::ok_or`], no error value is needed because the `true` statically guarantees that there is a
+	/// value inside.
+	///
+	/// # Example
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// let option = StaticOption::some(42);
+	/// assert_eq!(StaticResult::<i32, String, true>::new_ok(42), option.into_ok_result());
+	/// assert_eq!(StaticOption::some(42).ok_or(String::from("unused")), option.into_ok_result());
+	/// ```
+	pub const fn into_ok_result<E>(self) -> StaticResult<T, E, true> {
+		StaticResult::create_ok(self.inner())
+	}
+
 	/// See [`core::option::Option::and`].
 	///
 	/// Return `option_b`, dropping `self`.
@@ -87,6 +186,45 @@ impl<T> StaticOption<T, true> {
 	///
 	/// assert_eq!(StaticOption::none(), option_a.and(option_b));
 	/// ```
+	///
+	/// Discarded operands of `and`, `or`, `and_then` and `or_else` are dropped before the method returns, so in a
+	/// chain they are dropped from left to right:
+	/// ```
+	/// # use static_option::StaticOption;
+	/// use std::cell::RefCell;
+	/// use std::rc::Rc;
+	///
+	/// struct Logger(&'static str, Rc<RefCell<Vec<&'static str>>>);
+	///
+	/// impl Drop for Logger {
+	/// 	fn drop(&mut self) {
+	/// 		self.1.borrow_mut().push(self.0);
+	/// 	}
+	/// }
+	///
+	/// let log = Rc::new(RefCell::new(Vec::new()));
+	/// let a = StaticOption::some(Logger("a", log.clone()));
+	/// let b = StaticOption::some(Logger("b", log.clone()));
+	/// let c = StaticOption::some(Logger("c", log.clone()));
+	/// let result = a.and(b).or(c);
+	/// assert_eq!(vec!["a", "c"], *log.borrow());
+	/// result.drop();
+	/// assert_eq!(vec!["a", "c", "b"], *log.borrow());
+	///
+	/// log.borrow_mut().clear();
+	/// let a = StaticOption::some(Logger("a", log.clone()));
+	/// let c = Logger("c", log.clone());
+	/// let result = a
+	/// 	.and_then(|a| {
+	/// 		drop(a);
+	/// 		StaticOption::<Logger, false>::none()
+	/// 	})
+	/// 	.or_else(|| StaticOption::some(Logger("b", log.clone())))
+	/// 	.or(StaticOption::some(c));
+	/// assert_eq!(vec!["a", "c"], *log.borrow());
+	/// result.drop();
+	/// assert_eq!(vec!["a", "c", "b"], *log.borrow());
+	/// ```
 	pub fn and<U, const IS_SOME: bool>(self, option_b: StaticOption<U, IS_SOME>) -> StaticOption<U, IS_SOME> {
 		self.drop();
 		option_b
@@ -146,9 +284,10 @@ impl<T> StaticOption<T, true> {
 
 	/// See [`core::option::Option::or_else`].
 	///
-	/// Return `self`, ignoring `_fallback`.
+	/// Return `self`, dropping `_fallback` without calling it.
 	///
-	/// Warning: Since `_fallback` is ignored, any captured `StaticOption` will not be dropped.
+	/// Values captured by `_fallback` are dropped like the captures of any other closure, but see the caveats in the
+	/// [crate documentation](crate) for captured [`StaticOption`]s and [`StaticResult`]s, which are leaked.
 	///
 	/// Note that the `or_else` method on [`StaticOption<T, false>`] behaves differently.
 	///
@@ -164,6 +303,37 @@ impl<T> StaticOption<T, true> {
 	/// let option = StaticOption::some(42);
 	/// assert_eq!(StaticOption::some(42), option.or_else(|| StaticOption::none()));
 	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticOption;
+	/// # use std::rc::Rc;
+	/// let captured = Rc::new(1337);
+	/// let fallback = {
+	/// 	let captured = captured.clone();
+	/// 	move || StaticOption::some(*captured)
+	/// };
+	/// assert_eq!(2, Rc::strong_count(&captured));
+	///
+	/// let option = StaticOption::some(42);
+	/// assert_eq!(StaticOption::some(42), option.or_else(fallback));
+	/// assert_eq!(1, Rc::strong_count(&captured));
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticOption;
+	/// # use std::rc::Rc;
+	/// let captured = StaticOption::some(Rc::new(1337));
+	/// let counter = captured.inner_ref().clone();
+	///
+	/// let option = StaticOption::some(Rc::new(42));
+	/// let option = option.or_else(|| captured.clone());
+	/// assert_eq!(42, **option.inner_ref());
+	///
+	/// // `captured` was only borrowed, so it can still be dropped
+	/// captured.drop();
+	/// assert_eq!(1, Rc::strong_count(&counter));
+	/// # option.drop();
+	/// ```
 	pub fn or_else<F, const IS_SOME: bool>(self, _fallback: F) -> Self
 	where
 		F: FnOnce() -> StaticOption<T, IS_SOME>,
@@ -210,6 +380,249 @@ impl<T> StaticOption<T, true> {
 		swap(self.inner_mut(), &mut value);
 		StaticOption::some(value)
 	}
+
+	/// See [`core::option::Option::get_or_insert_with`].
+	///
+	/// Return a mutable borrow to the value in `self`. Since [`StaticOption<T, true>`] always contains a value,
+	/// `_function` is never called.
+	///
+	/// Note that this method only exists on [`StaticOption<T, true>`] because a [`StaticOption<T, false>`] can
+	/// never be modified to contain a value.
+	///
+	/// # Example
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let mut option = StaticOption::some(42);
+	/// let borrow: &mut i32 = option.get_or_insert_with(|| unreachable!());
+	/// assert_eq!(42, *borrow);
+	/// *borrow = 1337;
+	/// assert_eq!(StaticOption::some(1337), option);
+	/// ```
+	pub fn get_or_insert_with<F>(&mut self, _function: F) -> &mut T
+	where
+		F: FnOnce() -> T,
+	{
+		self.inner_mut()
+	}
+
+	/// Replace the value in `self` with the result of calling `function` with the current value. In contrast to
+	/// [`StaticOption::replace`], this doesn't require constructing a new value up front.
+	///
+	/// Note that this method only exists on [`StaticOption<T, true>`] because a [`StaticOption<T, false>`] doesn't
+	/// contain a value to pass to `function`.
+	///
+	/// # Panics
+	/// If `function` panics, the process is aborted instead of unwinding. Otherwise the value that was moved out
+	/// of `self` could be observed or dropped a second time during unwinding. With the `std` feature this calls
+	/// `std::process::abort`, without it a second panic during unwinding aborts the process.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let mut option = StaticOption::some(String::from("hello"));
+	/// option.replace_with(|mut text| {
+	/// 	text.push_str(" world");
+	/// 	text
+	/// });
+	/// assert_eq!(StaticOption::some(String::from("hello world")), option);
+	/// ```
+	///
+	/// The abort can be observed by running the panicking call in a child process:
+	/// ```
+	/// # use static_option::StaticOption;
+	/// use std::process::{Command, Stdio};
+	///
+	/// if std::env::var_os("STATIC_OPTION_REPLACE_WITH_PANIC").is_some() {
+	/// 	let mut option = StaticOption::some(String::from("hello"));
+	/// 	option.replace_with(|_| panic!("function panicked"));
+	/// 	unreachable!();
+	/// }
+	///
+	/// let status = Command::new(std::env::current_exe().unwrap())
+	/// 	.env("STATIC_OPTION_REPLACE_WITH_PANIC", "1")
+	/// 	.stderr(Stdio::null())
+	/// 	.status()
+	/// 	.unwrap();
+	/// assert!(!status.success());
+	/// // a panic that unwinds out of `main` exits with code 101 instead
+	/// assert_ne!(Some(101), status.code());
+	/// #[cfg(unix)]
+	/// {
+	/// 	use std::os::unix::process::ExitStatusExt;
+	/// 	const SIGABRT: i32 = 6;
+	/// 	assert_eq!(Some(SIGABRT), status.signal());
+	/// }
+	/// ```
+	pub fn replace_with<F>(&mut self, function: F)
+	where
+		F: FnOnce(T) -> T,
+	{
+		struct AbortOnUnwind;
+
+		impl Drop for AbortOnUnwind {
+			fn drop(&mut self) {
+				#[cfg(feature = "std")]
+				std::process::abort();
+				// without `std`, panicking while already unwinding is the only way to abort the process
+				#[cfg(not(feature = "std"))]
+				panic!("`StaticOption::replace_with` is aborting, because `function` panicked while the value was moved out");
+			}
+		}
+
+		let guard = AbortOnUnwind;
+		let slot: *mut T = self.inner_mut();
+		// SAFETY: The value is moved out of `slot` and a new value is written back before anything else can access
+		// `self`. If `function` panics, `guard` aborts the process during unwinding, so the moved out value can't
+		// be observed through `self`.
+		unsafe { slot.write(function(slot.read())) };
+		forget(guard);
+	}
+
+	/// Combine the value in `self` with the value in `other` using `function`.
+	///
+	/// Note that the `reduce` method on [`StaticOption<T, false>`] behaves differently.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let option = StaticOption::some(1);
+	/// assert_eq!(StaticOption::some(42), option.reduce(StaticOption::some(41), |a, b| a + b));
+	/// ```
+	pub fn reduce<F>(self, other: StaticOption<T, true>, function: F) -> StaticOption<T, true>
+	where
+		F: FnOnce(T, T) -> T,
+	{
+		StaticOption::new_some(function(self.inner(), other.inner()))
+	}
+
+	/// Call the fallible `mapper` function with the value contained in `self`, returning its error or an `ok`
+	/// result containing a [`StaticOption::some`] of the mapped value.
+	///
+	/// Note that the `try_map` method on [`StaticOption<T, false>`] behaves differently.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// let option = StaticOption::some(42);
+	/// let result = option.try_map(|value| StaticResult::<_, &'static str, true>::new_ok(value * 2));
+	/// assert_eq!(StaticResult::new_ok(StaticOption::some(84)), result);
+	/// ```
+	///
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// let option = StaticOption::some(42);
+	/// let result = option.try_map(|_| StaticResult::<i32, _, false>::new_err("error"));
+	/// assert_eq!(StaticResult::new_err("error"), result);
+	/// ```
+	pub fn try_map<U, E, F, const IS_OK: bool>(self, mapper: F) -> StaticResult<StaticOption<U, true>, E, IS_OK>
+	where
+		F: FnOnce(T) -> StaticResult<U, E, IS_OK>,
+	{
+		mapper(self.inner()).map(StaticOption::new_some)
+	}
+
+	/// Drop the contained value and return a [`StaticOption::none`] of the same type.
+	///
+	/// Note that the `clear` method on [`StaticOption<T, false>`] behaves differently.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// use std::rc::Rc;
+	///
+	/// let counter = Rc::new(());
+	/// let option = StaticOption::some(counter.clone());
+	/// assert_eq!(2, Rc::strong_count(&counter));
+	///
+	/// let none: StaticOption<Rc<()>, false> = option.clear();
+	/// assert_eq!(StaticOption::none(), none);
+	/// assert_eq!(1, Rc::strong_count(&counter));
+	/// ```
+	pub fn clear(self) -> StaticOption<T, false> {
+		self.drop();
+		StaticOption::none()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T> StaticOption<T, true> {
+	/// Collect the values of statically present options into a [`Vec`].
+	///
+	/// Since every [`StaticOption<T, true>`] contains a value, no value is dropped or skipped.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let options = [StaticOption::some(1), StaticOption::some(2), StaticOption::some(3)];
+	/// assert_eq!(vec![1, 2, 3], StaticOption::collect_present(options));
+	/// ```
+	pub fn collect_present<I>(iter: I) -> Vec<T>
+	where
+		I: IntoIterator<Item = StaticOption<T, true>>,
+	{
+		iter.into_iter().map(StaticOption::into_inner).collect()
+	}
+
+	/// Like [`StaticOption::collect_present`], but wraps the [`Vec`] in a [`StaticOption<Vec<T>, true>`], the
+	/// equivalent of collecting into an `Option<Vec<T>>` when every element is known to be present.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let options = (1..=3).map(StaticOption::some);
+	/// assert_eq!(StaticOption::some(vec![1, 2, 3]), StaticOption::collect_all(options));
+	///
+	/// let empty = std::iter::empty::<StaticOption<i32, true>>();
+	/// assert_eq!(StaticOption::some(Vec::new()), StaticOption::collect_all(empty));
+	/// ```
+	pub fn collect_all<I>(iter: I) -> StaticOption<Vec<T>, true>
+	where
+		I: IntoIterator<Item = StaticOption<T, true>>,
+	{
+		StaticOption::some(Self::collect_present(iter))
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
+	/// Move the contained value into a [`Box`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// use std::fmt::Display;
+	///
+	/// let option: StaticOption<Box<dyn Display>, true> = StaticOption::some(42).boxed().map(|value| value as _);
+	/// assert_eq!("42", option.inner_ref().to_string());
+	/// # option.drop();
+	///
+	/// assert_eq!(StaticOption::<Box<i32>, false>::none(), StaticOption::<i32, false>::none().boxed());
+	/// ```
+	pub fn boxed(self) -> StaticOption<Box<T>, IS_SOME> {
+		self.map(Box::new)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const IS_SOME: bool> StaticOption<Box<T>, IS_SOME> {
+	/// Move the contained value out of its [`Box`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// # use std::rc::Rc;
+	/// let counter = Rc::new(());
+	/// let option = StaticOption::some(counter.clone()).boxed().unboxed();
+	/// assert_eq!(2, Rc::strong_count(&counter));
+	///
+	/// option.drop();
+	/// assert_eq!(1, Rc::strong_count(&counter));
+	///
+	/// assert_eq!(StaticOption::<i32, false>::none(), StaticOption::<Box<i32>, false>::none().unboxed());
+	/// ```
+	pub fn unboxed(self) -> StaticOption<T, IS_SOME> {
+		self.map(|boxed| *boxed)
+	}
 }
 
 impl<T> StaticOption<T, false> {
@@ -219,6 +632,30 @@ impl<T> StaticOption<T, false> {
 		Self { none: () }
 	}
 
+	/// Convert an [`Option`] that is known to contain no value into a [`StaticOption<T, false>`].
+	///
+	/// # Panics
+	/// Panics with `message` if `option` is [`Some`], the value is dropped before panicking.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// assert_eq!(StaticOption::<i32, false>::none(), StaticOption::expect_none(None, "unexpected value"));
+	/// ```
+	///
+	/// ```should_panic
+	/// # use static_option::StaticOption;
+	/// StaticOption::<i32, false>::expect_none(Some(42), "unexpected value");
+	/// ```
+	pub fn expect_none(option: Option<T>, message: &str) -> Self {
+		if let Some(value) = option {
+			drop(value);
+			panic!("{}", message)
+		}
+
+		StaticOption::new_none()
+	}
+
 	/// See [`core::option::Option::and`].
 	///
 	/// Return [`StaticOption<U, false>::none()`], dropping `option_b`.
@@ -246,8 +683,10 @@ impl<T> StaticOption<T, false> {
 
 	/// See [`core::option::Option::and_then`].
 	///
-	/// Return [`StaticOption<U, false>::none()`], ignoring `_mapper`.
-	/// Warning: Since `_mapper` is ignored, any captured `StaticOption` will not be dropped.
+	/// Return [`StaticOption<U, false>::none()`], dropping `_mapper` without calling it.
+	///
+	/// Values captured by `_mapper` are dropped like the captures of any other closure, but see the caveats in the
+	/// [crate documentation](crate) for captured [`StaticOption`]s and [`StaticResult`]s, which are leaked.
 	///
 	/// Note that the `and_then` method on [`StaticOption<T, true>`] behaves differently.
 	///
@@ -263,6 +702,36 @@ impl<T> StaticOption<T, false> {
 	/// let option = StaticOption::<&'static str, false>::none();
 	/// assert_eq!(StaticOption::<i32, false>::none(), option.and_then(|_| StaticOption::<i32, false>::none()));
 	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticOption;
+	/// # use std::rc::Rc;
+	/// let captured = Rc::new(1337);
+	/// let mapper = {
+	/// 	let captured = captured.clone();
+	/// 	move |value: i32| StaticOption::some(value + *captured)
+	/// };
+	/// assert_eq!(2, Rc::strong_count(&captured));
+	///
+	/// let option = StaticOption::<i32, false>::none();
+	/// assert_eq!(StaticOption::none(), option.and_then(mapper));
+	/// assert_eq!(1, Rc::strong_count(&captured));
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticOption;
+	/// # use std::rc::Rc;
+	/// let captured = StaticOption::some(Rc::new(1337));
+	/// let counter = captured.inner_ref().clone();
+	///
+	/// let option = StaticOption::<i32, false>::none();
+	/// let mapped = option.and_then(|_| captured.clone());
+	/// assert_eq!(StaticOption::none(), mapped);
+	///
+	/// // `captured` was only borrowed, so it can still be dropped
+	/// captured.drop();
+	/// assert_eq!(1, Rc::strong_count(&counter));
+	/// ```
 	pub fn and_then<U, F, const IS_SOME: bool>(self, _mapper: F) -> StaticOption<U, false>
 	where
 		F: FnOnce(T) -> StaticOption<U, IS_SOME>,
@@ -320,8 +789,60 @@ impl<T> StaticOption<T, false> {
 		// self doesn't need to be dropped since it is none
 		fallback()
 	}
+
+	/// Return `other`, dropping `_function` without calling it.
+	///
+	/// Note that the `reduce` method on [`StaticOption<T, true>`] behaves differently.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let option = StaticOption::none();
+	/// assert_eq!(StaticOption::some(41), option.reduce(StaticOption::some(41), |a: i32, b| a + b));
+	/// ```
+	pub fn reduce<F>(self, other: StaticOption<T, true>, _function: F) -> StaticOption<T, true>
+	where
+		F: FnOnce(T, T) -> T,
+	{
+		// self doesn't need to be dropped since it is none
+		other
+	}
+
+	/// Return an `ok` result containing [`StaticOption::none`], dropping `_mapper` without calling it.
+	///
+	/// Note that the `try_map` method on [`StaticOption<T, true>`] behaves differently.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// let option = StaticOption::<i32, false>::none();
+	/// let result = option.try_map(|_| -> StaticResult<i32, &'static str, false> { unreachable!() });
+	/// assert_eq!(StaticResult::new_ok(StaticOption::none()), result);
+	/// ```
+	pub fn try_map<U, E, F, const IS_OK: bool>(self, _mapper: F) -> StaticResult<StaticOption<U, false>, E, true>
+	where
+		F: FnOnce(T) -> StaticResult<U, E, IS_OK>,
+	{
+		// self doesn't need to be dropped since it is none
+		StaticResult::new_ok(StaticOption::none())
+	}
+
+	/// Return `self` unchanged.
+	///
+	/// Note that the `clear` method on [`StaticOption<T, true>`] behaves differently.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let option = StaticOption::<i32, false>::none();
+	/// assert_eq!(StaticOption::none(), option.clear());
+	/// ```
+	pub const fn clear(self) -> StaticOption<T, false> {
+		self
+	}
 }
 
+#[allow(clippy::needless_lifetimes)]
 impl<'a, T, const IS_SOME: bool> StaticOption<&'a T, IS_SOME> {
 	/// See [`core::option::Option::copied`].
 	///
@@ -380,61 +901,166 @@ impl<'a, T, const IS_SOME: bool> StaticOption<&'a T, IS_SOME> {
 			StaticOption::new_none()
 		}
 	}
-}
 
-impl<T, const IS_SOME: bool> StaticOption<StaticOption<T, IS_SOME>, true> {
-	/// See [`core::option::Option::flatten`].
-	///
-	/// Return the contained [`StaticOption`].
+	/// Compare the references in `self` and `other` by address instead of by value.
 	///
-	/// Note that the `flatten` method on [`StaticOption<StaticOption<T, IS_SOME>, false>`] behaves differently.
+	/// [`PartialEq`] compares the referenced values, this checks whether both options point to the same referent
+	/// using [`core::ptr::eq`]. Two [`StaticOption::none`] are always equal.
 	///
 	/// # Examples
 	/// ```
 	/// # use static_option::StaticOption;
-	/// let option = StaticOption::some(StaticOption::some(42));
-	/// assert_eq!(StaticOption::some(42), option.flatten());
+	/// let first = 42;
+	/// let second = 42;
+	///
+	/// let option = StaticOption::some(&first);
+	/// assert!(option == StaticOption::some(&second));
+	/// assert!(!option.by_ref_eq(&StaticOption::some(&second)));
+	/// assert!(option.by_ref_eq(&StaticOption::some(&first)));
 	/// ```
 	///
 	/// ```
 	/// # use static_option::StaticOption;
-	/// let option = StaticOption::some(StaticOption::<i32, false>::none());
-	/// assert_eq!(StaticOption::<i32, false>::none(), option.flatten());
+	/// let option = StaticOption::<&i32, false>::none();
+	/// assert!(option.by_ref_eq(&StaticOption::none()));
 	/// ```
-	pub const fn flatten(self) -> StaticOption<T, IS_SOME> {
-		self.into_inner()
+	pub fn by_ref_eq(&self, other: &Self) -> bool {
+		if IS_SOME {
+			core::ptr::eq(*self.as_inner(), *other.as_inner())
+		} else {
+			true
+		}
 	}
 }
 
-impl<T, const IS_SOME: bool> StaticOption<StaticOption<T, IS_SOME>, false> {
-	/// See [`core::option::Option::flatten`].
-	///
-	/// Return [`StaticOption::none()`].
+impl<T, const IS_SOME: bool> StaticOption<&mut T, IS_SOME> {
+	/// See [`core::option::Option::copied`].
 	///
-	/// Note that the `flatten` method on [`StaticOption<StaticOption<T, IS_SOME>, true>`] behaves differently.
+	/// Take a [`StaticOption`] containing a mutable reference and return a new [`StaticOption`]
+	/// with an owned copy.
 	///
 	/// # Examples
 	/// ```
 	/// # use static_option::StaticOption;
-	/// let option = StaticOption::<StaticOption<i32, true>, false>::none();
-	/// assert_eq!(StaticOption::<i32, false>::none(), option.flatten());
+	/// let mut option = StaticOption::some(42);
+	/// assert_eq!(StaticOption::some(42), option.as_mut().copied());
 	/// ```
 	///
 	/// ```
 	/// # use static_option::StaticOption;
-	/// let option = StaticOption::<StaticOption::<i32, false>, false>::none();
-	/// assert_eq!(StaticOption::<i32, false>::none(), option.flatten());
+	/// let option = StaticOption::<&mut i32, false>::none();
+	/// assert_eq!(StaticOption::<i32, false>::none(), option.copied());
 	/// ```
-	pub const fn flatten(self) -> StaticOption<T, false> {
-		// self doesn't need to be dropped since it is none
-		StaticOption::none()
-	}
-}
-
-impl<T, E, const IS_OK: bool> StaticOption<StaticResult<T, E, IS_OK>, true> {
-	/// See [`core::option::Option::transpose`].
-	///
-	/// If the contained [`StaticResult`] is `ok`, return an `ok` result with a [`StaticOption::some`] of the `ok` value inside.
+	pub fn copied(self) -> StaticOption<T, IS_SOME>
+	where
+		T: Copy,
+	{
+		if IS_SOME {
+			StaticOption::new_some(*self.inner())
+		} else {
+			StaticOption::new_none()
+		}
+	}
+
+	/// See [`core::option::Option::cloned`].
+	///
+	/// Take a [`StaticOption`] containing a mutable reference and return a new [`StaticOption`]
+	/// with an owned clone.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let mut option = StaticOption::some(String::from("hello"));
+	/// let mut cloned = option.as_mut().cloned();
+	/// cloned.inner_mut().push_str(" world");
+	/// assert_eq!(StaticOption::some(String::from("hello")), option);
+	/// assert_eq!(StaticOption::some(String::from("hello world")), cloned);
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let option = StaticOption::<&mut String, false>::none();
+	/// assert_eq!(StaticOption::<String, false>::none(), option.cloned());
+	/// ```
+	pub fn cloned(self) -> StaticOption<T, IS_SOME>
+	where
+		T: Clone,
+	{
+		if IS_SOME {
+			StaticOption::new_some(T::clone(self.inner()))
+		} else {
+			StaticOption::new_none()
+		}
+	}
+}
+
+impl<T, const IS_SOME: bool> StaticOption<StaticOption<T, IS_SOME>, true> {
+	/// See [`core::option::Option::flatten`].
+	///
+	/// Return the contained [`StaticOption`].
+	///
+	/// Note that the `flatten` method on [`StaticOption<StaticOption<T, IS_SOME>, false>`] behaves differently.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let option = StaticOption::some(StaticOption::some(42));
+	/// assert_eq!(StaticOption::some(42), option.flatten());
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let option = StaticOption::some(StaticOption::<i32, false>::none());
+	/// assert_eq!(StaticOption::<i32, false>::none(), option.flatten());
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticOption;
+	/// const OPTION: StaticOption<StaticOption<i32, true>, true> = StaticOption::some(StaticOption::some(42));
+	/// const FLATTENED: StaticOption<i32, true> = OPTION.flatten();
+	/// assert_eq!(StaticOption::some(42), FLATTENED);
+	/// ```
+	pub const fn flatten(self) -> StaticOption<T, IS_SOME> {
+		self.into_inner()
+	}
+}
+
+impl<T, const IS_SOME: bool> StaticOption<StaticOption<T, IS_SOME>, false> {
+	/// See [`core::option::Option::flatten`].
+	///
+	/// Return [`StaticOption::none()`].
+	///
+	/// Note that the `flatten` method on [`StaticOption<StaticOption<T, IS_SOME>, true>`] behaves differently.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let option = StaticOption::<StaticOption<i32, true>, false>::none();
+	/// assert_eq!(StaticOption::<i32, false>::none(), option.flatten());
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let option = StaticOption::<StaticOption::<i32, false>, false>::none();
+	/// assert_eq!(StaticOption::<i32, false>::none(), option.flatten());
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticOption;
+	/// const OPTION: StaticOption<StaticOption<i32, true>, false> = StaticOption::none();
+	/// const FLATTENED: StaticOption<i32, false> = OPTION.flatten();
+	/// assert_eq!(StaticOption::none(), FLATTENED);
+	/// ```
+	pub const fn flatten(self) -> StaticOption<T, false> {
+		// self doesn't need to be dropped since it is none
+		StaticOption::none()
+	}
+}
+
+impl<T, E, const IS_OK: bool> StaticOption<StaticResult<T, E, IS_OK>, true> {
+	/// See [`core::option::Option::transpose`].
+	///
+	/// If the contained [`StaticResult`] is `ok`, return an `ok` result with a [`StaticOption::some`] of the `ok` value inside.
 	/// Otherwise return a [`StaticResult`] with the original `error` value.
 	///
 	/// Note that the `transpose` method on [`StaticOption<StaticResult<T, E, IS_OK>, false>`] behaves differently.
@@ -452,6 +1078,41 @@ impl<T, E, const IS_OK: bool> StaticOption<StaticResult<T, E, IS_OK>, true> {
 	/// let option = StaticOption::some(StaticResult::<i32, &'static str, false>::new_err("error"));
 	/// assert_eq!(StaticResult::new_err("error"), option.transpose())
 	/// ```
+	///
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// const OPTION: StaticOption<StaticResult<i32, &'static str, false>, true> =
+	/// 	StaticOption::some(StaticResult::new_err("error"));
+	/// const TRANSPOSED: StaticResult<StaticOption<i32, true>, &'static str, false> = OPTION.transpose();
+	/// assert_eq!(StaticResult::new_err("error"), TRANSPOSED);
+	/// ```
+	///
+	/// Transposing twice is an identity, neither `T` nor `E` is leaked or dropped twice. Since the
+	/// nested [`StaticOption`] and [`StaticResult`] don't implement [`Drop`], they have to be dropped layer by layer:
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// # use std::rc::Rc;
+	/// let value = Rc::new(42);
+	/// let error = Rc::new("error");
+	///
+	/// let option = StaticOption::some(StaticResult::<_, Rc<&str>, true>::new_ok(value.clone()));
+	/// let roundtrip = option.transpose().transpose();
+	/// let expected = StaticOption::some(StaticResult::new_ok(value.clone()));
+	/// assert_eq!(expected, roundtrip);
+	/// expected.into_inner().drop();
+	/// assert_eq!(2, Rc::strong_count(&value));
+	/// roundtrip.into_inner().drop();
+	/// assert_eq!(1, Rc::strong_count(&value));
+	///
+	/// let option = StaticOption::some(StaticResult::<Rc<i32>, _, false>::new_err(error.clone()));
+	/// let roundtrip = option.transpose().transpose();
+	/// let expected = StaticOption::some(StaticResult::new_err(error.clone()));
+	/// assert_eq!(expected, roundtrip);
+	/// expected.into_inner().drop();
+	/// assert_eq!(2, Rc::strong_count(&error));
+	/// roundtrip.into_inner().drop();
+	/// assert_eq!(1, Rc::strong_count(&error));
+	/// ```
 	pub const fn transpose(self) -> StaticResult<StaticOption<T, true>, E, IS_OK> {
 		let result = self.into_inner();
 		if IS_OK {
@@ -482,13 +1143,123 @@ impl<T, E, const IS_OK: bool> StaticOption<StaticResult<T, E, IS_OK>, false> {
 	/// let option = StaticOption::<StaticResult<i32, &'static str, false>, false>::none();
 	/// assert_eq!(StaticResult::new_ok(StaticOption::none()), option.transpose());
 	/// ```
+	///
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// const OPTION: StaticOption<StaticResult<i32, &'static str, true>, false> = StaticOption::none();
+	/// const TRANSPOSED: StaticResult<StaticOption<i32, false>, &'static str, true> = OPTION.transpose();
+	/// assert_eq!(StaticResult::new_ok(StaticOption::none()), TRANSPOSED);
+	/// ```
+	///
+	/// Transposing twice results in a [`StaticOption::none`] again:
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// let option = StaticOption::<StaticResult<i32, &'static str, true>, false>::none();
+	/// assert_eq!(StaticOption::none(), option.transpose().transpose());
+	///
+	/// let option = StaticOption::<StaticResult<i32, &'static str, false>, false>::none();
+	/// assert_eq!(StaticOption::<StaticResult<i32, &'static str, true>, false>::none(), option.transpose().transpose());
+	/// ```
 	pub const fn transpose(self) -> StaticResult<StaticOption<T, false>, E, true> {
 		// self doesn't need to be dropped since it is none
 		StaticResult::new_ok(StaticOption::none())
 	}
 }
 
+impl<T, E, const IS_SOME: bool> StaticOption<Result<T, E>, IS_SOME> {
+	/// See [`core::option::Option::transpose`].
+	///
+	/// Like `transpose` on a [`StaticOption`] containing a [`StaticResult`], but for a [`StaticOption`] containing
+	/// a [`core::result::Result`]. A `some` with an `Ok` value becomes an `Ok` with a [`StaticOption::some`],
+	/// a `some` with an `Err` becomes that `Err` and a `none` becomes an `Ok` with a [`StaticOption::none`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let option = StaticOption::some(Ok::<i32, &'static str>(42));
+	/// assert_eq!(Ok(StaticOption::some(42)), option.transpose_core());
+	///
+	/// let option = StaticOption::some(Err::<i32, &'static str>("error"));
+	/// assert_eq!(Err("error"), option.transpose_core());
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let option = StaticOption::<Result<i32, &'static str>, false>::none();
+	/// assert_eq!(Ok(StaticOption::none()), option.transpose_core());
+	/// ```
+	pub fn transpose_core(self) -> Result<StaticOption<T, IS_SOME>, E> {
+		if IS_SOME {
+			match self.inner() {
+				Ok(value) => Ok(StaticOption::new_some(value)),
+				Err(error) => Err(error),
+			}
+		} else {
+			// self doesn't need to be dropped since it is none
+			Ok(StaticOption::new_none())
+		}
+	}
+}
+
+impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME>
+where
+	T: Copy,
+{
+	/// Like [`StaticOption::unwrap_or`], but usable in const contexts.
+	///
+	/// [`StaticOption::unwrap_or`] has to drop `default` if `self` contains a value, which isn't possible in a
+	/// const fn for arbitrary types. [`Copy`] types don't need to be dropped, so this is restricted to them.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// const VALUE: i32 = StaticOption::some(42).some_or(1337);
+	/// assert_eq!(42, VALUE);
+	///
+	/// const DEFAULT: i32 = StaticOption::none().some_or(1337);
+	/// assert_eq!(1337, DEFAULT);
+	/// ```
+	pub const fn some_or(self, default: T) -> T {
+		if IS_SOME {
+			self.inner()
+		} else {
+			default
+		}
+	}
+
+	/// Copy the contained value out of a borrowed [`StaticOption`], same as `self.as_ref().copied()` but usable in
+	/// const contexts.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// const SOME: StaticOption<i32, true> = StaticOption::some(42);
+	/// const COPIED: StaticOption<i32, true> = SOME.copied_ref();
+	/// assert_eq!(StaticOption::some(42), COPIED);
+	///
+	/// const NONE: StaticOption<i32, false> = StaticOption::none();
+	/// assert_eq!(StaticOption::none(), NONE.copied_ref());
+	/// ```
+	pub const fn copied_ref(&self) -> StaticOption<T, IS_SOME> {
+		if IS_SOME {
+			StaticOption::new_some(*self.as_inner())
+		} else {
+			StaticOption::new_none()
+		}
+	}
+}
+
 impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
+	/// Whether this type contains a value, readable in const code without an instance.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// const _: () = assert!(StaticOption::<i32, true>::IS_SOME);
+	/// const _: () = assert!(!StaticOption::<i32, false>::IS_SOME);
+	/// ```
+	pub const IS_SOME: bool = IS_SOME;
+
 	/// See [`core::option::Option::is_some`].
 	///
 	/// Return `true` if this [`StaticOption`] contains a value, `false` otherwise.
@@ -521,6 +1292,25 @@ impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
 		!IS_SOME
 	}
 
+	/// Return the number of values in this [`StaticOption`], `1` if it contains a value, `0` otherwise.
+	///
+	/// This treats a [`StaticOption`] like a collection of zero or one values and is determined by the
+	/// type alone.
+	///
+	/// # Example
+	/// ```
+	/// # use static_option::StaticOption;
+	/// assert_eq!(1, StaticOption::some(42).count());
+	/// assert_eq!(0, StaticOption::<i32, false>::none().count());
+	///
+	/// const OPTION: StaticOption<i32, true> = StaticOption::some(42);
+	/// const COUNT: usize = OPTION.count();
+	/// assert_eq!(1, COUNT);
+	/// ```
+	pub const fn count(&self) -> usize {
+		IS_SOME as usize
+	}
+
 	/// See [`core::option::Option::as_ref`].
 	///
 	/// Given a reference to a [`StaticOption`], returns an owned [`StaticOption`] containing a reference
@@ -538,7 +1328,14 @@ impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
 	/// let option = StaticOption::<i32, false>::none();
 	/// assert_eq!(StaticOption::<&i32, false>::none(), option.as_ref());
 	/// ```
-	pub fn as_ref(&self) -> StaticOption<&T, IS_SOME> {
+	///
+	/// ```
+	/// # use static_option::StaticOption;
+	/// const OPTION: StaticOption<i32, true> = StaticOption::some(42);
+	/// const REFERENCE: StaticOption<&i32, true> = OPTION.as_ref();
+	/// assert_eq!(StaticOption::some(&42), REFERENCE);
+	/// ```
+	pub const fn as_ref(&self) -> StaticOption<&T, IS_SOME> {
 		if IS_SOME {
 			StaticOption::new_some(self.as_inner())
 		} else {
@@ -546,6 +1343,29 @@ impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
 		}
 	}
 
+	/// Return a reference to the contained value or `default` if `self` doesn't contain a value.
+	///
+	/// This is usable in const contexts, unlike the closure based variants.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// const OPTION: StaticOption<i32, true> = StaticOption::some(42);
+	/// const VALUE: &i32 = OPTION.some_ref_or(&1337);
+	/// assert_eq!(42, *VALUE);
+	///
+	/// const NONE: StaticOption<i32, false> = StaticOption::none();
+	/// const DEFAULT: &i32 = NONE.some_ref_or(&1337);
+	/// assert_eq!(1337, *DEFAULT);
+	/// ```
+	pub const fn some_ref_or<'a>(&'a self, default: &'a T) -> &'a T {
+		if IS_SOME {
+			self.as_inner()
+		} else {
+			default
+		}
+	}
+
 	/// See [`core::option::Option::as_ref`].
 	///
 	/// Given a mutable reference to a [`StaticOption`], returns an owned [`StaticOption`] containing a mutable reference
@@ -566,6 +1386,7 @@ impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
 	/// let mut option = StaticOption::<i32, false>::none();
 	/// assert_eq!(StaticOption::<&mut i32, false>::none(), option.as_mut());
 	/// ```
+	// NOTE: Can't be a `const fn` because mutable references in const fn require Rust 1.83
 	pub fn as_mut(&mut self) -> StaticOption<&mut T, IS_SOME> {
 		if IS_SOME {
 			StaticOption::new_some(self.as_inner_mut())
@@ -593,6 +1414,45 @@ impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
 		}
 	}
 
+	/// Like [`StaticOption::as_pin_mut`], but returns an [`Option`] that can be matched on directly, e.g. in a
+	/// [`Future::poll`] implementation.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// use std::future::{ready, Future, Ready};
+	/// use std::pin::Pin;
+	/// use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+	///
+	/// struct Optional<F, const IS_SOME: bool>(StaticOption<F, IS_SOME>);
+	///
+	/// impl<F: Future, const IS_SOME: bool> Future for Optional<F, IS_SOME> {
+	/// 	type Output = Option<F::Output>;
+	///
+	/// 	fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+	/// 		// SAFETY: The field is never moved out of `self`.
+	/// 		let future = unsafe { self.map_unchecked_mut(|optional| &mut optional.0) };
+	/// 		match future.pin_get_mut() {
+	/// 			Some(future) => future.poll(context).map(Some),
+	/// 			None => Poll::Ready(None),
+	/// 		}
+	/// 	}
+	/// }
+	///
+	/// const VTABLE: RawWakerVTable = RawWakerVTable::new(|_| RawWaker::new(std::ptr::null(), &VTABLE), |_| {}, |_| {}, |_| {});
+	/// let waker = unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) };
+	/// let mut context = Context::from_waker(&waker);
+	///
+	/// let mut some = Optional(StaticOption::some(ready(42)));
+	/// assert_eq!(Poll::Ready(Some(42)), Pin::new(&mut some).poll(&mut context));
+	///
+	/// let mut none = Optional(StaticOption::<Ready<i32>, false>::none());
+	/// assert_eq!(Poll::Ready(None), Pin::new(&mut none).poll(&mut context));
+	/// ```
+	pub fn pin_get_mut(self: Pin<&mut Self>) -> Option<Pin<&mut T>> {
+		self.as_pin_mut().into_option()
+	}
+
 	pub fn ok_or<E>(self, error: E) -> StaticResult<T, E, IS_SOME> {
 		if IS_SOME {
 			StaticResult::create_ok(self.inner())
@@ -601,6 +1461,14 @@ impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
 		}
 	}
 
+	/// See [`core::option::Option::ok_or_else`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// assert_eq!(StaticResult::new_ok(42), StaticOption::some(42).ok_or_else(|| -> &str { unreachable!() }));
+	/// assert_eq!(StaticResult::<i32, _, false>::new_err("error"), StaticOption::none().ok_or_else(|| "error"));
+	/// ```
 	pub fn ok_or_else<E, F>(self, error: F) -> StaticResult<T, E, IS_SOME>
 	where
 		F: FnOnce() -> E,
@@ -612,6 +1480,29 @@ impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
 		}
 	}
 
+	/// Like [`StaticOption::ok_or`], but uses the [`Default`] value of `E` as the error if `self` doesn't contain a
+	/// value.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// let option = StaticOption::some(42);
+	/// assert_eq!(StaticResult::<i32, String, true>::new_ok(42), option.ok_or_default());
+	///
+	/// let option = StaticOption::<i32, false>::none();
+	/// assert_eq!(StaticResult::<i32, String, false>::new_err(String::new()), option.ok_or_default());
+	/// ```
+	pub fn ok_or_default<E>(self) -> StaticResult<T, E, IS_SOME>
+	where
+		E: Default,
+	{
+		if IS_SOME {
+			StaticResult::create_ok(self.inner())
+		} else {
+			StaticResult::create_err(E::default())
+		}
+	}
+
 	pub fn unwrap_or_default(self) -> T
 	where
 		T: Default,
@@ -623,19 +1514,54 @@ impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
 		}
 	}
 
+	/// See [`core::option::Option::expect`].
+	///
+	/// # Panics
+	/// Panics with `message` if `self` is a [`StaticOption<T, false>`].
+	///
+	/// Because `message` is only known at runtime, the panic goes through [`core::fmt::Arguments`] like every panic
+	/// on stable Rust. Passing a `&str` directly to the panic handler requires the unstable `core::panicking`
+	/// internals. Binaries that must not contain any formatting code should use [`StaticOption::unwrap`] instead,
+	/// which panics with a constant message.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// assert_eq!(42, StaticOption::some(42).expect("no value"));
+	///
+	/// let payload = std::panic::catch_unwind(|| StaticOption::<u32, false>::none().expect("no value")).unwrap_err();
+	/// assert_eq!(Some(&String::from("no value")), payload.downcast_ref::<String>());
+	/// ```
 	pub fn expect(self, message: &str) -> T {
 		if IS_SOME {
 			self.inner()
 		} else {
+			// NOTE: The 2021 edition `panic!` lowers `"{}"` with a single argument to `panic_display`, which is the
+			// cheapest formatting path reachable on stable.
 			panic!("{}", message)
 		}
 	}
 
+	/// See [`core::option::Option::unwrap`].
+	///
+	/// The panic message is the same for every `T`, so it doesn't need to be formatted or duplicated per type.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// assert_eq!(42, StaticOption::some(42).unwrap());
+	///
+	/// let payload = std::panic::catch_unwind(|| StaticOption::<u32, false>::none().unwrap()).unwrap_err();
+	/// assert_eq!(
+	/// 	Some(&"called `unwrap()` on a `StaticOption::none` value"),
+	/// 	payload.downcast_ref::<&'static str>()
+	/// );
+	/// ```
 	pub fn unwrap(self) -> T {
 		if IS_SOME {
 			self.inner()
 		} else {
-			panic!("called `unwrap()` on {}", type_name::<Self>())
+			panic!("called `unwrap()` on a `StaticOption::none` value")
 		}
 	}
 
@@ -680,6 +1606,14 @@ impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
 		}
 	}
 
+	/// See [`core::option::Option::map`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// assert_eq!(StaticOption::some(84), StaticOption::some(42).map(|value| value * 2));
+	/// assert_eq!(StaticOption::none(), StaticOption::<i32, false>::none().map(|value| value * 2));
+	/// ```
 	pub fn map<U, F>(self, mapper: F) -> StaticOption<U, IS_SOME>
 	where
 		F: FnOnce(T) -> U,
@@ -691,6 +1625,62 @@ impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
 		}
 	}
 
+	/// Like [`StaticOption::map`], but also sets `ran` to whether `mapper` was called, which is always `IS_SOME`.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let mut ran = false;
+	/// assert_eq!(StaticOption::some(84), StaticOption::some(42).map_counted(|value| value * 2, &mut ran));
+	/// assert!(ran);
+	///
+	/// let mut ran = true;
+	/// let none = StaticOption::<i32, false>::none();
+	/// assert_eq!(StaticOption::none(), none.map_counted(|value| value * 2, &mut ran));
+	/// assert!(!ran);
+	/// ```
+	pub fn map_counted<U, F>(self, mapper: F, ran: &mut bool) -> StaticOption<U, IS_SOME>
+	where
+		F: FnOnce(T) -> U,
+	{
+		*ran = IS_SOME;
+		self.map(mapper)
+	}
+
+	/// Like [`StaticOption::map`], but also passes `accumulator` to `mapper`. If `self` doesn't contain a value,
+	/// `accumulator` is left untouched.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let mut total = 0;
+	/// let mut doubled = Vec::new();
+	/// let options = [StaticOption::some(1), StaticOption::some(2), StaticOption::some(3)];
+	/// for option in options {
+	/// 	let option = option.map_with(&mut total, |total, value| {
+	/// 		*total += value;
+	/// 		value * 2
+	/// 	});
+	/// 	doubled.push(option.into_inner());
+	/// }
+	/// assert_eq!(vec![2, 4, 6], doubled);
+	/// assert_eq!(6, total);
+	///
+	/// let option = StaticOption::<i32, false>::none();
+	/// assert_eq!(StaticOption::none(), option.map_with(&mut total, |total, value| *total += value));
+	/// assert_eq!(6, total);
+	/// ```
+	pub fn map_with<A, U, F>(self, accumulator: &mut A, mapper: F) -> StaticOption<U, IS_SOME>
+	where
+		F: FnOnce(&mut A, T) -> U,
+	{
+		if IS_SOME {
+			StaticOption::new_some(mapper(accumulator, self.inner()))
+		} else {
+			StaticOption::new_none()
+		}
+	}
+
 	pub fn map_or<U, F>(self, default: U, mapper: F) -> U
 	where
 		F: FnOnce(T) -> U,
@@ -702,6 +1692,62 @@ impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
 		}
 	}
 
+	/// See [`core::option::Option::map_or_else`].
+	///
+	/// Exactly one of `mapper` or `default` is called, the other one is dropped without calling it. Values captured
+	/// by either closure are dropped like the captures of any other closure, but see the caveats in the
+	/// [crate documentation](crate) for captured [`StaticOption`]s and [`StaticResult`]s, which are leaked.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// # use std::rc::Rc;
+	/// let counter = Rc::new(());
+	/// let default = {
+	/// 	let counter = counter.clone();
+	/// 	move || -> usize { panic!("{:?}", counter) }
+	/// };
+	/// let mapper = {
+	/// 	let counter = counter.clone();
+	/// 	move |value: usize| value + Rc::strong_count(&counter)
+	/// };
+	/// assert_eq!(3, Rc::strong_count(&counter));
+	///
+	/// assert_eq!(45, StaticOption::some(42).map_or_else(default, mapper));
+	/// assert_eq!(1, Rc::strong_count(&counter));
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticOption;
+	/// # use std::rc::Rc;
+	/// let counter = Rc::new(());
+	/// let default = {
+	/// 	let counter = counter.clone();
+	/// 	move || Rc::strong_count(&counter)
+	/// };
+	/// let mapper = {
+	/// 	let counter = counter.clone();
+	/// 	move |_: usize| -> usize { panic!("{:?}", counter) }
+	/// };
+	/// assert_eq!(3, Rc::strong_count(&counter));
+	///
+	/// assert_eq!(3, StaticOption::<usize, false>::none().map_or_else(default, mapper));
+	/// assert_eq!(1, Rc::strong_count(&counter));
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticOption;
+	/// # use std::rc::Rc;
+	/// let captured = StaticOption::some(Rc::new(1337));
+	/// let counter = captured.inner_ref().clone();
+	///
+	/// let value = StaticOption::some(42).map_or_else(|| **captured.inner_ref(), |value| value);
+	/// assert_eq!(42, value);
+	///
+	/// // `captured` was only borrowed, so it can still be dropped
+	/// captured.drop();
+	/// assert_eq!(1, Rc::strong_count(&counter));
+	/// ```
 	pub fn map_or_else<U, D, F>(self, default: D, mapper: F) -> U
 	where
 		F: FnOnce(T) -> U,
@@ -714,6 +1760,115 @@ impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
 		}
 	}
 
+	/// Call the `mapper` function with the value contained in `self` and return the resulting [`Option`],
+	/// or return [`None`] without calling `mapper` if `self` doesn't contain a value.
+	///
+	/// This is like [`core::option::Option::and_then`] for functions returning a [`core::option::Option`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let option = StaticOption::some("42");
+	/// assert_eq!(Some(42), option.and_then_option(|text| text.parse::<i32>().ok()));
+	///
+	/// let option = StaticOption::some("hello");
+	/// assert_eq!(None, option.and_then_option(|text| text.parse::<i32>().ok()));
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let option = StaticOption::<&'static str, false>::none();
+	/// assert_eq!(None, option.and_then_option(|_| -> Option<i32> { unreachable!() }));
+	/// ```
+	pub fn and_then_option<U, F>(self, mapper: F) -> Option<U>
+	where
+		F: FnOnce(T) -> Option<U>,
+	{
+		if IS_SOME {
+			mapper(self.inner())
+		} else {
+			None
+		}
+	}
+
+	/// Call `mapper` with the contained value and return the [`StaticOption::some`] it returns, or return
+	/// [`StaticOption::none`] without calling `mapper` if `self` doesn't contain a value.
+	///
+	/// Unlike `and_then`, this is available for any `IS_SOME` and keeps it, because `mapper` always returns a value.
+	/// This makes chaining over options that are present if `self` is present possible in generic code.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let option = StaticOption::some(42);
+	/// assert_eq!(StaticOption::some(84), option.and_then_while(|value| StaticOption::some(value * 2)));
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let option = StaticOption::<i32, false>::none();
+	/// assert_eq!(StaticOption::none(), option.and_then_while(|_| -> StaticOption<i32, true> { unreachable!() }));
+	/// ```
+	pub fn and_then_while<U, F>(self, mapper: F) -> StaticOption<U, IS_SOME>
+	where
+		F: FnOnce(T) -> StaticOption<U, true>,
+	{
+		if IS_SOME {
+			StaticOption::new_some(mapper(self.inner()).into_inner())
+		} else {
+			StaticOption::new_none()
+		}
+	}
+
+	/// Same as [`StaticOption::and_then_option`], for when the mapping is thought of as a `filter_map` that might
+	/// drop the value, which can't be expressed as a [`StaticOption<U, IS_SOME>`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let option = StaticOption::some(42);
+	/// assert_eq!(Some(21), option.map_or_none(|value| (value % 2 == 0).then(|| value / 2)));
+	///
+	/// let option = StaticOption::some(43);
+	/// assert_eq!(None, option.map_or_none(|value| (value % 2 == 0).then(|| value / 2)));
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let option = StaticOption::<i32, false>::none();
+	/// assert_eq!(None, option.map_or_none(|_| -> Option<i32> { unreachable!() }));
+	/// ```
+	pub fn map_or_none<U, F>(self, mapper: F) -> Option<U>
+	where
+		F: FnOnce(T) -> Option<U>,
+	{
+		self.and_then_option(mapper)
+	}
+
+	/// Call `function` if `self` doesn't contain a value and return `self` unchanged.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let mut calls = 0;
+	/// let option = StaticOption::some(42).inspect_none(|| calls += 1);
+	/// assert_eq!(StaticOption::some(42), option);
+	/// assert_eq!(0, calls);
+	///
+	/// let option = StaticOption::<i32, false>::none().inspect_none(|| calls += 1);
+	/// assert_eq!(StaticOption::none(), option);
+	/// assert_eq!(1, calls);
+	/// ```
+	pub fn inspect_none<F>(self, function: F) -> Self
+	where
+		F: FnOnce(),
+	{
+		if !IS_SOME {
+			function();
+		}
+		self
+	}
+
 	pub fn iter(&self) -> Iter<&T> {
 		self.as_ref().into_iter()
 	}
@@ -737,7 +1892,42 @@ impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
 		}
 	}
 
-	pub fn as_option(&self) -> Option<&T> {
+	/// Convert into a [`Poll`], treating a contained value as [`Poll::Ready`] and a missing value as
+	/// [`Poll::Pending`].
+	///
+	/// Returning [`Poll::Pending`] from a [`Future`] means it has to arrange for the task to be woken up again,
+	/// this method doesn't do that.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// use std::task::Poll;
+	///
+	/// assert_eq!(Poll::Ready(42), StaticOption::some(42).into_poll());
+	/// assert_eq!(Poll::<i32>::Pending, StaticOption::none().into_poll());
+	/// ```
+	pub const fn into_poll(self) -> Poll<T> {
+		if IS_SOME {
+			Poll::Ready(self.inner())
+		} else {
+			Poll::Pending
+		}
+	}
+
+	/// Borrow the contained value as an [`Option`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// const SOME: StaticOption<i32, true> = StaticOption::some(42);
+	/// const SOME_REFERENCE: Option<&i32> = SOME.as_option();
+	/// assert_eq!(Some(&42), SOME_REFERENCE);
+	///
+	/// const NONE: StaticOption<i32, false> = StaticOption::none();
+	/// const NONE_REFERENCE: Option<&i32> = NONE.as_option();
+	/// assert_eq!(None, NONE_REFERENCE);
+	/// ```
+	pub const fn as_option(&self) -> Option<&T> {
 		if IS_SOME {
 			Some(self.as_inner())
 		} else {
@@ -745,6 +1935,23 @@ impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
 		}
 	}
 
+	/// Mutably borrow the contained value as an [`Option`].
+	///
+	/// Unlike [`StaticOption::as_option`], this isn't a `const fn` because mutable references in `const fn` aren't
+	/// stable in the minimum supported Rust version.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let mut some = StaticOption::some(41);
+	/// if let Some(value) = some.as_mut_option() {
+	/// 	*value += 1;
+	/// }
+	/// assert_eq!(StaticOption::some(42), some);
+	///
+	/// let mut none = StaticOption::<i32, false>::none();
+	/// assert_eq!(None, none.as_mut_option());
+	/// ```
 	pub fn as_mut_option(&mut self) -> Option<&mut T> {
 		if IS_SOME {
 			Some(self.as_inner_mut())
@@ -753,6 +1960,122 @@ impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
 		}
 	}
 
+	/// Clone the contained value into an [`Option`] without consuming `self`.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let some = StaticOption::some(String::from("value"));
+	/// assert_eq!(Some(String::from("value")), some.to_option());
+	/// assert_eq!(StaticOption::some(String::from("value")), some);
+	///
+	/// let none = StaticOption::<String, false>::none();
+	/// assert_eq!(None, none.to_option());
+	/// ```
+	pub fn to_option(&self) -> Option<T>
+	where
+		T: Clone,
+	{
+		self.as_option().cloned()
+	}
+
+	/// Clone the contained value without consuming `self`, returning `default` if there is no value.
+	///
+	/// Note that this is different from `Rc::unwrap_or_clone` and `Arc::unwrap_or_clone`, which consume the pointer.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let some = StaticOption::some(String::from("value"));
+	/// assert_eq!("value", some.unwrap_or_clone(String::from("default")));
+	/// assert_eq!(StaticOption::some(String::from("value")), some);
+	///
+	/// let none = StaticOption::<String, false>::none();
+	/// assert_eq!("default", none.unwrap_or_clone(String::from("default")));
+	/// ```
+	pub fn unwrap_or_clone(&self, default: T) -> T
+	where
+		T: Clone,
+	{
+		match self.as_option() {
+			Some(value) => value.clone(),
+			None => default,
+		}
+	}
+
+	/// Borrow the contained value, alias of [`StaticOption::as_option`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// const SOME: StaticOption<i32, true> = StaticOption::some(42);
+	/// const SOME_REFERENCE: Option<&i32> = SOME.get();
+	/// assert_eq!(Some(&42), SOME_REFERENCE);
+	///
+	/// const NONE: StaticOption<i32, false> = StaticOption::none();
+	/// const NONE_REFERENCE: Option<&i32> = NONE.get();
+	/// assert_eq!(None, NONE_REFERENCE);
+	/// ```
+	pub const fn get(&self) -> Option<&T> {
+		self.as_option()
+	}
+
+	/// Mutably borrow the contained value, alias of [`StaticOption::as_mut_option`].
+	///
+	/// Unlike [`StaticOption::get`], this can't be a `const fn`, see [`StaticOption::as_mut_option`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let mut some = StaticOption::some(42);
+	/// if let Some(value) = some.get_mut() {
+	/// 	*value += 1;
+	/// }
+	/// assert_eq!(StaticOption::some(43), some);
+	///
+	/// let mut none = StaticOption::<i32, false>::none();
+	/// assert_eq!(None, none.get_mut());
+	/// ```
+	pub fn get_mut(&mut self) -> Option<&mut T> {
+		self.as_mut_option()
+	}
+
+	/// Look at the contained value without consuming `self`, alias of [`StaticOption::as_option`] for method chains.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// const SOME: StaticOption<i32, true> = StaticOption::some(42);
+	/// const PEEKED: Option<&i32> = SOME.peek();
+	/// assert_eq!(Some(&42), PEEKED);
+	///
+	/// let option = StaticOption::some(String::from("hello"));
+	/// assert_eq!(Some(5), option.peek().map(String::len));
+	/// assert_eq!(StaticOption::some(6), option.map(|text| text + "!").map(|text| text.len()));
+	///
+	/// assert_eq!(None, StaticOption::<i32, false>::none().peek());
+	/// ```
+	pub const fn peek(&self) -> Option<&T> {
+		self.as_option()
+	}
+
+	/// Mutably look at the contained value without consuming `self`, alias of [`StaticOption::as_mut_option`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOption;
+	/// let mut option = StaticOption::some(vec![1, 2]);
+	/// if let Some(values) = option.peek_mut() {
+	/// 	values.push(3);
+	/// }
+	/// assert_eq!(StaticOption::some(vec![1, 2, 3]), option);
+	///
+	/// assert_eq!(None, StaticOption::<i32, false>::none().peek_mut());
+	/// ```
+	pub fn peek_mut(&mut self) -> Option<&mut T> {
+		self.as_mut_option()
+	}
+
 	// Equivalent to `some` but doesn't require explicit `true` as type parameter.
 	#[inline(always)]
 	pub(crate) const fn new_some(value: T) -> Self {
@@ -782,11 +2105,13 @@ impl<T, const IS_SOME: bool> StaticOption<T, IS_SOME> {
 
 	// Equivalent to `inner_ref` but doesn't require explicit `true` as type parameter.
 	#[inline(always)]
-	pub(crate) fn as_inner(&self) -> &T {
+	pub(crate) const fn as_inner(&self) -> &T {
 		// SAFETY: StaticOption<T, true> can only be constructed with a value inside (tracked by the `true`)
-		// and the assert ensures that the `some` union field is only accessed when it is initialized
+		// and the assert ensures that the `some` union field is only accessed when it is initialized.
+		// The pointer cast is used instead of `Deref`, which isn't const, and is sound because `ManuallyDrop<T>`
+		// is `#[repr(transparent)]`.
 		assert!(IS_SOME); // gets optimized away
-		unsafe { &self.some }
+		unsafe { &*(&self.some as *const ManuallyDrop<T> as *const T) }
 	}
 
 	// Equivalent to `inner_mut` but doesn't require explicit `true` as type parameter.
@@ -805,6 +2130,32 @@ impl<T> Default for StaticOption<T, false> {
 	}
 }
 
+/// Create a [`StaticOption<T, true>`] containing `T::default()`.
+///
+/// # Example
+/// ```
+/// # use static_option::StaticOption;
+/// assert_eq!(StaticOption::some(0), StaticOption::<i32, true>::default());
+///
+/// #[derive(Default)]
+/// struct Config {
+/// 	retries: StaticOption<u8, true>,
+/// 	timeout: StaticOption<u32, false>,
+/// }
+///
+/// let config = Config::default();
+/// assert_eq!(StaticOption::some(0), config.retries);
+/// assert_eq!(StaticOption::none(), config.timeout);
+/// ```
+impl<T> Default for StaticOption<T, true>
+where
+	T: Default,
+{
+	fn default() -> Self {
+		StaticOption::new_some(T::default())
+	}
+}
+
 impl<T, const IS_SOME: bool> From<StaticOption<T, IS_SOME>> for Option<T> {
 	fn from(static_option: StaticOption<T, IS_SOME>) -> Self {
 		static_option.into_option()
@@ -820,6 +2171,22 @@ where
 	}
 }
 
+/// Formats like a tuple struct named `StaticOption::some` or `StaticOption::none`, also in the alternate `{:#?}` form.
+///
+/// # Examples
+/// ```
+/// # use static_option::{StaticOption, StaticResult};
+/// let nested = StaticOption::some(StaticResult::<i32, &str, true>::new_ok(1));
+/// assert_eq!("StaticOption::some(StaticResult::ok(1))", format!("{:?}", nested));
+/// assert_eq!(
+/// 	"StaticOption::some(\n    StaticResult::ok(\n        1,\n    ),\n)",
+/// 	format!("{:#?}", nested)
+/// );
+///
+/// let none = StaticOption::<i32, false>::none();
+/// assert_eq!("StaticOption::none", format!("{:?}", none));
+/// assert_eq!("StaticOption::none", format!("{:#?}", none));
+/// ```
 impl<T, const IS_SOME: bool> Debug for StaticOption<T, IS_SOME>
 where
 	T: Debug,
@@ -866,6 +2233,81 @@ impl<T> From<T> for StaticOption<T, true> {
 	}
 }
 
+/// Polls the contained future if there is one. A [`StaticOption<F, false>`] resolves to `none` immediately.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticOption;
+/// # use std::future::Future;
+/// # use std::pin::Pin;
+/// # use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+/// #
+/// # fn block_on<F: Future>(mut future: F) -> F::Output {
+/// # 	const VTABLE: RawWakerVTable = RawWakerVTable::new(|_| RawWaker::new(std::ptr::null(), &VTABLE), |_| {}, |_| {}, |_| {});
+/// # 	let waker = unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) };
+/// # 	let mut context = Context::from_waker(&waker);
+/// # 	let mut future = unsafe { Pin::new_unchecked(&mut future) };
+/// # 	loop {
+/// # 		if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+/// # 			return output;
+/// # 		}
+/// # 	}
+/// # }
+/// let some = block_on(async { StaticOption::some(async { 42 }).await });
+/// assert_eq!(StaticOption::some(42), some);
+///
+/// let none = block_on(async { StaticOption::<std::future::Pending<u32>, false>::none().await });
+/// assert_eq!(StaticOption::<u32, false>::none(), none);
+/// ```
+impl<F, const IS_SOME: bool> Future for StaticOption<F, IS_SOME>
+where
+	F: Future,
+{
+	type Output = StaticOption<F::Output, IS_SOME>;
+
+	fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+		if IS_SOME {
+			self.as_pin_mut().inner().poll(context).map(StaticOption::new_some)
+		} else {
+			Poll::Ready(StaticOption::new_none())
+		}
+	}
+}
+
+/// Hashes exactly like the corresponding [`Option`], including its discriminant.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticOption;
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::{Hash, Hasher};
+///
+/// fn hash(value: impl Hash) -> u64 {
+/// 	let mut hasher = DefaultHasher::new();
+/// 	value.hash(&mut hasher);
+/// 	hasher.finish()
+/// }
+///
+/// assert_eq!(hash(Some(42)), hash(StaticOption::some(42)));
+/// assert_eq!(hash(None::<i32>), hash(StaticOption::<i32, false>::none()));
+/// assert_ne!(hash(42), hash(StaticOption::some(42)));
+/// ```
+///
+/// Since a [`StaticOption::none`] only hashes the discriminant, its hash doesn't depend on `T`:
+/// ```
+/// # use static_option::StaticOption;
+/// # use std::collections::hash_map::DefaultHasher;
+/// # use std::hash::{Hash, Hasher};
+/// # fn hash(value: impl Hash) -> u64 {
+/// # 	let mut hasher = DefaultHasher::new();
+/// # 	value.hash(&mut hasher);
+/// # 	hasher.finish()
+/// # }
+/// assert_eq!(
+/// 	hash(StaticOption::<i32, false>::none()),
+/// 	hash(StaticOption::<String, false>::none())
+/// );
+/// ```
 impl<T, const IS_SOME: bool> Hash for StaticOption<T, IS_SOME>
 where
 	T: Hash,
@@ -914,3 +2356,32 @@ where
 }
 
 impl<T, const IS_SOME: bool> Copy for StaticOption<T, IS_SOME> where T: Copy {}
+
+/// Sums the values like [`Option`] does, returning [`None`] if any element is `none`.
+///
+/// The result is a dynamic [`Option`] because an empty iterator of [`StaticOption<T, false>`] sums up to
+/// [`Some`], so the presence of the sum isn't known statically.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticOption;
+/// let options = [StaticOption::some(1), StaticOption::some(2), StaticOption::some(3)];
+/// assert_eq!(Some(6), options.into_iter().sum::<Option<i32>>());
+///
+/// let options = [StaticOption::<i32, false>::none(), StaticOption::none()];
+/// assert_eq!(None, options.into_iter().sum::<Option<i32>>());
+///
+/// let options: [StaticOption<i32, false>; 0] = [];
+/// assert_eq!(Some(0), options.into_iter().sum::<Option<i32>>());
+/// ```
+impl<T, U, const IS_SOME: bool> Sum<StaticOption<U, IS_SOME>> for Option<T>
+where
+	T: Sum<U>,
+{
+	fn sum<I>(iter: I) -> Self
+	where
+		I: Iterator<Item = StaticOption<U, IS_SOME>>,
+	{
+		iter.map(StaticOption::into_option).sum()
+	}
+}
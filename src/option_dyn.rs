@@ -0,0 +1,292 @@
+use crate::StaticOption;
+
+/// A [`StaticOption`] whose presence is only known at runtime.
+///
+/// This allows moving between [`Option`] and the static types without `unsafe`, e.g. for the results of
+/// operations like filtering where the presence of the value depends on the value itself.
+///
+/// The variants are in the same order as those of [`Option`], so the derived [`PartialOrd`] and [`Ord`] match.
+///
+/// Like [`StaticOption`], this doesn't implement [`Drop`], use [`StaticOptionDyn::drop`] instead.
+///
+/// # Examples
+/// ```
+/// # use static_option::{StaticOption, StaticOptionDyn};
+/// match StaticOptionDyn::from_option(Some(42)) {
+/// 	StaticOptionDyn::Some(option) => assert_eq!(StaticOption::some(42), option),
+/// 	StaticOptionDyn::None(_) => unreachable!(),
+/// }
+///
+/// assert_eq!(Some(42), StaticOptionDyn::from_option(Some(42)).into_option());
+/// assert_eq!(None::<i32>, StaticOptionDyn::from_option(None).into_option());
+/// ```
+#[must_use = "Call `.drop()` if you don't use the StaticOptionDyn, otherwise it's contents never get dropped."]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StaticOptionDyn<T> {
+	None(StaticOption<T, false>),
+	Some(StaticOption<T, true>),
+}
+
+impl<T> StaticOptionDyn<T> {
+	/// Convert an [`Option`] into a [`StaticOptionDyn`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::{StaticOption, StaticOptionDyn};
+	/// assert_eq!(StaticOptionDyn::Some(StaticOption::some(42)), StaticOptionDyn::from_option(Some(42)));
+	/// assert_eq!(StaticOptionDyn::None(StaticOption::<i32, false>::none()), StaticOptionDyn::from_option(None));
+	/// ```
+	pub fn from_option(option: Option<T>) -> Self {
+		match option {
+			Some(value) => StaticOptionDyn::Some(StaticOption::some(value)),
+			None => StaticOptionDyn::None(StaticOption::none()),
+		}
+	}
+
+	/// Build a [`StaticOptionDyn`] from a presence flag and a value, e.g. for formats that encode presence as a
+	/// `bool` followed by a payload. If `is_present` is `false`, `value` is dropped.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::{StaticOption, StaticOptionDyn};
+	/// use std::rc::Rc;
+	///
+	/// let counter = Rc::new(42);
+	/// let present = StaticOptionDyn::from_parts(true, counter.clone());
+	/// assert_eq!(StaticOptionDyn::Some(StaticOption::some(Rc::new(42))), present);
+	/// assert_eq!(2, Rc::strong_count(&counter));
+	///
+	/// let absent = StaticOptionDyn::from_parts(false, counter.clone());
+	/// assert_eq!(StaticOptionDyn::None(StaticOption::none()), absent);
+	/// assert_eq!(2, Rc::strong_count(&counter));
+	/// # present.drop();
+	/// ```
+	pub fn from_parts(is_present: bool, value: T) -> Self {
+		if is_present {
+			StaticOptionDyn::Some(StaticOption::some(value))
+		} else {
+			StaticOptionDyn::None(StaticOption::none())
+		}
+	}
+
+	/// Convert a [`StaticOptionDyn`] into an [`Option`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::{StaticOption, StaticOptionDyn};
+	/// assert_eq!(Some(42), StaticOptionDyn::Some(StaticOption::some(42)).into_option());
+	/// assert_eq!(None::<i32>, StaticOptionDyn::None(StaticOption::none()).into_option());
+	/// ```
+	pub fn into_option(self) -> Option<T> {
+		match self {
+			StaticOptionDyn::Some(option) => option.into_option(),
+			StaticOptionDyn::None(option) => option.into_option(),
+		}
+	}
+
+	/// See [`core::option::Option::is_some`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOptionDyn;
+	/// assert!(StaticOptionDyn::from_option(Some(42)).is_some());
+	/// assert!(!StaticOptionDyn::<i32>::from_option(None).is_some());
+	/// ```
+	pub const fn is_some(&self) -> bool {
+		matches!(self, StaticOptionDyn::Some(_))
+	}
+
+	/// See [`core::option::Option::is_none`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOptionDyn;
+	/// assert!(!StaticOptionDyn::from_option(Some(42)).is_none());
+	/// assert!(StaticOptionDyn::<i32>::from_option(None).is_none());
+	/// ```
+	pub const fn is_none(&self) -> bool {
+		matches!(self, StaticOptionDyn::None(_))
+	}
+
+	/// See [`core::option::Option::as_ref`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOptionDyn;
+	/// let option = StaticOptionDyn::from_option(Some(String::from("hello")));
+	/// assert_eq!(Some(5), option.as_ref().map(|text| text.len()).into_option());
+	/// # option.drop();
+	/// ```
+	pub fn as_ref(&self) -> StaticOptionDyn<&T> {
+		match self {
+			StaticOptionDyn::Some(option) => StaticOptionDyn::Some(option.as_ref()),
+			StaticOptionDyn::None(option) => StaticOptionDyn::None(option.as_ref()),
+		}
+	}
+
+	/// See [`core::option::Option::as_mut`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOptionDyn;
+	/// let mut option = StaticOptionDyn::from_option(Some(42));
+	/// option.as_mut().map(|value| *value += 1);
+	/// assert_eq!(Some(43), option.into_option());
+	/// ```
+	pub fn as_mut(&mut self) -> StaticOptionDyn<&mut T> {
+		match self {
+			StaticOptionDyn::Some(option) => StaticOptionDyn::Some(option.as_mut()),
+			StaticOptionDyn::None(option) => StaticOptionDyn::None(option.as_mut()),
+		}
+	}
+
+	/// See [`core::option::Option::map`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOptionDyn;
+	/// assert_eq!(Some(84), StaticOptionDyn::from_option(Some(42)).map(|value| value * 2).into_option());
+	/// assert_eq!(None, StaticOptionDyn::<i32>::from_option(None).map(|value| value * 2).into_option());
+	/// ```
+	pub fn map<U, F>(self, mapper: F) -> StaticOptionDyn<U>
+	where
+		F: FnOnce(T) -> U,
+	{
+		match self {
+			StaticOptionDyn::Some(option) => StaticOptionDyn::Some(option.map(mapper)),
+			StaticOptionDyn::None(option) => StaticOptionDyn::None(option.map(mapper)),
+		}
+	}
+
+	/// See [`core::option::Option::and_then`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOptionDyn;
+	/// let half = |value: i32| StaticOptionDyn::from_option((value % 2 == 0).then(|| value / 2));
+	/// assert_eq!(Some(21), StaticOptionDyn::from_option(Some(42)).and_then(half).into_option());
+	/// assert_eq!(None, StaticOptionDyn::from_option(Some(43)).and_then(half).into_option());
+	/// assert_eq!(None, StaticOptionDyn::from_option(None).and_then(half).into_option());
+	/// ```
+	pub fn and_then<U, F>(self, function: F) -> StaticOptionDyn<U>
+	where
+		F: FnOnce(T) -> StaticOptionDyn<U>,
+	{
+		match self {
+			StaticOptionDyn::Some(option) => function(option.into_inner()),
+			StaticOptionDyn::None(_) => StaticOptionDyn::None(StaticOption::none()),
+		}
+	}
+
+	/// See [`core::option::Option::filter`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOptionDyn;
+	/// assert_eq!(Some(42), StaticOptionDyn::from_option(Some(42)).filter(|value| *value > 0).into_option());
+	/// assert_eq!(None, StaticOptionDyn::from_option(Some(42)).filter(|value| *value < 0).into_option());
+	/// ```
+	pub fn filter<P>(self, predicate: P) -> Self
+	where
+		P: FnOnce(&T) -> bool,
+	{
+		match self {
+			StaticOptionDyn::Some(option) if predicate(option.as_inner()) => StaticOptionDyn::Some(option),
+			StaticOptionDyn::Some(option) => {
+				option.drop();
+				StaticOptionDyn::None(StaticOption::none())
+			}
+			none @ StaticOptionDyn::None(_) => none,
+		}
+	}
+
+	/// See [`core::option::Option::unwrap`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOptionDyn;
+	/// assert_eq!(42, StaticOptionDyn::from_option(Some(42)).unwrap());
+	/// ```
+	///
+	/// ```should_panic
+	/// # use static_option::StaticOptionDyn;
+	/// StaticOptionDyn::<i32>::from_option(None).unwrap();
+	/// ```
+	pub fn unwrap(self) -> T {
+		match self {
+			StaticOptionDyn::Some(option) => option.into_inner(),
+			StaticOptionDyn::None(option) => option.unwrap(),
+		}
+	}
+
+	/// See [`core::option::Option::unwrap_or`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOptionDyn;
+	/// assert_eq!(42, StaticOptionDyn::from_option(Some(42)).unwrap_or(1337));
+	/// assert_eq!(1337, StaticOptionDyn::from_option(None).unwrap_or(1337));
+	/// ```
+	pub fn unwrap_or(self, default: T) -> T {
+		match self {
+			StaticOptionDyn::Some(option) => option.unwrap_or(default),
+			StaticOptionDyn::None(option) => option.unwrap_or(default),
+		}
+	}
+
+	/// See [`core::option::Option::unwrap_or_else`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticOptionDyn;
+	/// assert_eq!(42, StaticOptionDyn::from_option(Some(42)).unwrap_or_else(|| 1337));
+	/// assert_eq!(1337, StaticOptionDyn::from_option(None).unwrap_or_else(|| 1337));
+	/// ```
+	pub fn unwrap_or_else<F>(self, function: F) -> T
+	where
+		F: FnOnce() -> T,
+	{
+		match self {
+			StaticOptionDyn::Some(option) => option.unwrap_or_else(function),
+			StaticOptionDyn::None(option) => option.unwrap_or_else(function),
+		}
+	}
+
+	/// Drop the contained value if there is one.
+	pub fn drop(self) {
+		match self {
+			StaticOptionDyn::Some(option) => option.drop(),
+			StaticOptionDyn::None(option) => option.drop(),
+		}
+	}
+}
+
+impl<T> Default for StaticOptionDyn<T> {
+	fn default() -> Self {
+		StaticOptionDyn::None(StaticOption::none())
+	}
+}
+
+impl<T> From<Option<T>> for StaticOptionDyn<T> {
+	fn from(option: Option<T>) -> Self {
+		StaticOptionDyn::from_option(option)
+	}
+}
+
+impl<T> From<StaticOptionDyn<T>> for Option<T> {
+	fn from(option: StaticOptionDyn<T>) -> Self {
+		option.into_option()
+	}
+}
+
+impl<T> From<StaticOption<T, true>> for StaticOptionDyn<T> {
+	fn from(option: StaticOption<T, true>) -> Self {
+		StaticOptionDyn::Some(option)
+	}
+}
+
+impl<T> From<StaticOption<T, false>> for StaticOptionDyn<T> {
+	fn from(option: StaticOption<T, false>) -> Self {
+		StaticOptionDyn::None(option)
+	}
+}
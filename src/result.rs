@@ -1,9 +1,10 @@
 use crate::{Iter, StaticOption};
-use core::any::type_name;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::cmp::Ordering;
-use core::fmt::{Debug, Formatter};
+use core::fmt::{Debug, Display, Formatter};
 use core::hash::{Hash, Hasher};
-use core::mem::ManuallyDrop;
+use core::mem::{swap, ManuallyDrop};
 use core::ops::{Deref, DerefMut};
 
 #[must_use = "Call `.drop()` if you don't use the `StaticResult`, otherwise it's contents never get dropped."]
@@ -12,11 +13,83 @@ pub union StaticResult<T, E, const IS_OK: bool> {
 	pub(crate) error: ManuallyDrop<E>,
 }
 
+/// A [`StaticResult`] that always contains an `ok` value, shorthand for `StaticResult<T, E, true>`.
+///
+/// # Examples
+/// ```
+/// # use static_option::{OkResult, StaticResult};
+/// fn parse(text: &str) -> OkResult<usize, &'static str> {
+/// 	OkResult::new_ok(text.len())
+/// }
+///
+/// assert_eq!(StaticResult::new_ok(5), parse("hello"));
+/// let result: Result<usize, &str> = parse("hello").into();
+/// assert_eq!(Ok(5), result);
+/// ```
+pub type OkResult<T, E> = StaticResult<T, E, true>;
+
+/// A [`StaticResult`] that always contains an error, shorthand for `StaticResult<T, E, false>`.
+///
+/// # Examples
+/// ```
+/// # use static_option::{ErrResult, StaticResult};
+/// fn fail() -> ErrResult<usize, &'static str> {
+/// 	ErrResult::new_err("error")
+/// }
+///
+/// assert_eq!(StaticResult::new_err("error"), fail());
+/// let result: Result<usize, &str> = fail().into();
+/// assert_eq!(Err("error"), result);
+/// ```
+pub type ErrResult<T, E> = StaticResult<T, E, false>;
+
 impl<T, E> StaticResult<T, E, true> {
 	pub const fn new_ok(ok: T) -> StaticResult<T, E, true> {
 		StaticResult::create_ok(ok)
 	}
 
+	/// See [`core::result::Result::and`].
+	///
+	/// Discarded operands of `and`, `or`, `and_then` and `or_else` are dropped before the method returns, so in a
+	/// chain they are dropped from left to right.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// use std::cell::RefCell;
+	/// use std::rc::Rc;
+	///
+	/// struct Logger(&'static str, Rc<RefCell<Vec<&'static str>>>);
+	///
+	/// impl Drop for Logger {
+	/// 	fn drop(&mut self) {
+	/// 		self.1.borrow_mut().push(self.0);
+	/// 	}
+	/// }
+	///
+	/// let log = Rc::new(RefCell::new(Vec::new()));
+	/// let a = StaticResult::<Logger, Logger, true>::new_ok(Logger("a", log.clone()));
+	/// let b = StaticResult::<Logger, Logger, false>::new_err(Logger("b", log.clone()));
+	/// let c = StaticResult::<Logger, Logger, true>::new_ok(Logger("c", log.clone()));
+	/// let d = StaticResult::<Logger, Logger, true>::new_ok(Logger("d", log.clone()));
+	/// let result = a.and(b).or(c).and(d);
+	/// assert_eq!(vec!["a", "b", "c"], *log.borrow());
+	/// result.drop();
+	/// assert_eq!(vec!["a", "b", "c", "d"], *log.borrow());
+	///
+	/// log.borrow_mut().clear();
+	/// let a = StaticResult::<Logger, Logger, true>::new_ok(Logger("a", log.clone()));
+	/// let result = a
+	/// 	.and_then(|a| StaticResult::<Logger, Logger, false>::new_err(a))
+	/// 	.or_else(|a| {
+	/// 		drop(a);
+	/// 		StaticResult::<Logger, Logger, true>::new_ok(Logger("b", log.clone()))
+	/// 	})
+	/// 	.or_else(|_| -> StaticResult<Logger, Logger, true> { unreachable!() });
+	/// assert_eq!(vec!["a"], *log.borrow());
+	/// result.drop();
+	/// assert_eq!(vec!["a", "b"], *log.borrow());
+	/// ```
 	pub fn and<U, const IS_SOME: bool>(self, res: StaticResult<U, E, IS_SOME>) -> StaticResult<U, E, IS_SOME> {
 		self.drop();
 		res
@@ -29,6 +102,42 @@ impl<T, E> StaticResult<T, E, true> {
 		op(self.into_ok())
 	}
 
+	/// Combine the `ok` value of `self` with the `ok` value of `other` using `function`, returning the error of
+	/// `other` if it isn't `ok`.
+	///
+	/// Note that the `zip_with` method on [`StaticResult<T, E, false>`] behaves differently.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, &str, true>::new_ok(1);
+	/// let other = StaticResult::<i32, &str, true>::new_ok(41);
+	/// assert_eq!(StaticResult::new_ok(42), result.zip_with(other, |a, b| a + b));
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, &str, true>::new_ok(1);
+	/// let other = StaticResult::<i32, &str, false>::new_err("other");
+	/// let zipped = result.zip_with(other, |_, _: i32| -> i32 { unreachable!() });
+	/// assert_eq!(StaticResult::new_err("other"), zipped);
+	/// ```
+	pub fn zip_with<U, R, F, const IS_OK: bool>(
+		self,
+		other: StaticResult<U, E, IS_OK>,
+		function: F,
+	) -> StaticResult<R, E, IS_OK>
+	where
+		F: FnOnce(T, U) -> R,
+	{
+		if IS_OK {
+			StaticResult::create_ok(function(self.into_ok(), other.inner_ok()))
+		} else {
+			self.drop();
+			StaticResult::create_err(other.inner_error())
+		}
+	}
+
 	pub fn or<F, const IS_SOME: bool>(self, res: StaticResult<T, F, IS_SOME>) -> StaticResult<T, F, true> {
 		res.drop();
 		StaticResult::new_ok(self.into_ok())
@@ -45,6 +154,19 @@ impl<T, E> StaticResult<T, E, true> {
 		self.inner_ok()
 	}
 
+	/// Take out the `ok` value, same as [`StaticResult::into_ok`]. This is named like
+	/// [`StaticOption::into_inner`] and, unlike `unwrap`, doesn't require `E: Debug`.
+	///
+	/// # Example
+	/// ```
+	/// # use static_option::StaticResult;
+	/// const OK: i32 = StaticResult::<i32, (), true>::new_ok(42).into_inner();
+	/// assert_eq!(42, OK);
+	/// ```
+	pub const fn into_inner(self) -> T {
+		self.inner_ok()
+	}
+
 	pub fn ok_ref(&self) -> &T {
 		self.as_ok()
 	}
@@ -52,9 +174,115 @@ impl<T, E> StaticResult<T, E, true> {
 	pub fn ok_mut(&mut self) -> &mut T {
 		self.as_ok_mut()
 	}
+
+	/// Transpose a [`core::option::Option`] of an `ok` [`StaticResult`] into an `ok` [`StaticResult`]
+	/// of a [`core::option::Option`], turning `Some(ok(value))` into `ok(Some(value))` and `None` into `ok(None)`.
+	///
+	/// Note that the `transpose_option` function on [`StaticResult<T, E, false>`] behaves differently.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let option = Some(StaticResult::<i32, &'static str, true>::new_ok(42));
+	/// assert_eq!(StaticResult::new_ok(Some(42)), StaticResult::<_, _, true>::transpose_option(option));
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let option = None::<StaticResult<i32, &'static str, true>>;
+	/// assert_eq!(StaticResult::new_ok(None), StaticResult::<_, _, true>::transpose_option(option));
+	/// ```
+	pub fn transpose_option(option: Option<Self>) -> StaticResult<Option<T>, E, true> {
+		StaticResult::new_ok(option.map(StaticResult::into_ok))
+	}
+
+	/// Fold the `ok` values of `iter` into `init` using `function`, stopping at the first error.
+	///
+	/// The items can be anything that converts into a [`Result`], so [`StaticResult`]s of both polarities that were
+	/// converted with [`StaticResult::into_result`] can be mixed with each other and with [`Result`]s. This is only
+	/// defined on [`StaticResult<T, E, true>`] so that the const generic parameter doesn't need to be specified.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let results = (1..=3).map(StaticResult::<i32, &str, true>::new_ok);
+	/// assert_eq!(Ok(6), StaticResult::try_fold_ok(results, 0, |sum, value| sum + value));
+	///
+	/// let results = std::iter::empty::<StaticResult<i32, &str, false>>();
+	/// assert_eq!(Ok(0), StaticResult::try_fold_ok(results, 0, |sum, value| sum + value));
+	/// ```
+	///
+	/// Folding stops at the first error:
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let results = [
+	/// 	StaticResult::<i32, &str, true>::new_ok(1).into_result(),
+	/// 	StaticResult::<i32, &str, true>::new_ok(2).into_result(),
+	/// 	StaticResult::<i32, &str, false>::new_err("x").into_result(),
+	/// 	StaticResult::<i32, &str, true>::new_ok(3).into_result(),
+	/// ];
+	/// let mut consumed = 0;
+	/// let results = results.into_iter().inspect(|_| consumed += 1);
+	/// assert_eq!(Err("x"), StaticResult::try_fold_ok(results, 0, |sum, value| sum + value));
+	/// assert_eq!(3, consumed);
+	///
+	/// let mut consumed = 0;
+	/// let results = [Ok(1), Ok(2), Err("x"), Ok(3)].into_iter().inspect(|_| consumed += 1);
+	/// assert_eq!(Err("x"), StaticResult::try_fold_ok(results, 0, |sum, value| sum + value));
+	/// assert_eq!(3, consumed);
+	/// ```
+	pub fn try_fold_ok<I, B, F>(iter: I, init: B, mut function: F) -> Result<B, E>
+	where
+		I: IntoIterator,
+		I::Item: Into<Result<T, E>>,
+		F: FnMut(B, T) -> B,
+	{
+		iter.into_iter()
+			.try_fold(init, |accumulator, result| Ok(function(accumulator, result.into()?)))
+	}
 }
 
 impl<T, E, const IS_SOME: bool> StaticResult<StaticOption<T, IS_SOME>, E, true> {
+	/// See [`core::result::Result::transpose`].
+	///
+	/// If the contained [`StaticOption`] is `some`, return a [`StaticOption::some`] with an `ok` result of the value inside.
+	/// Otherwise return [`StaticOption::none`].
+	///
+	/// Note that the `transpose` method on [`StaticResult<StaticOption<T, IS_SOME>, E, false>`] behaves differently.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// let result = StaticResult::<_, &'static str, true>::new_ok(StaticOption::some(42));
+	/// assert_eq!(StaticOption::some(StaticResult::new_ok(42)), result.transpose());
+	/// ```
+	///
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// const RESULT: StaticResult<StaticOption<i32, false>, &'static str, true> = StaticResult::new_ok(StaticOption::none());
+	/// const TRANSPOSED: StaticOption<StaticResult<i32, &'static str, true>, false> = RESULT.transpose();
+	/// assert_eq!(StaticOption::none(), TRANSPOSED);
+	/// ```
+	///
+	/// Transposing twice is an identity, neither `T` nor `E` is leaked or dropped twice. Since the
+	/// nested [`StaticOption`] and [`StaticResult`] don't implement [`Drop`], they have to be dropped layer by layer:
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// # use std::rc::Rc;
+	/// let value = Rc::new(42);
+	///
+	/// let result = StaticResult::<_, Rc<&str>, true>::new_ok(StaticOption::some(value.clone()));
+	/// let roundtrip = result.transpose().transpose();
+	/// let expected = StaticResult::new_ok(StaticOption::some(value.clone()));
+	/// assert_eq!(expected, roundtrip);
+	/// expected.into_ok().drop();
+	/// assert_eq!(2, Rc::strong_count(&value));
+	/// roundtrip.into_ok().drop();
+	/// assert_eq!(1, Rc::strong_count(&value));
+	///
+	/// let result = StaticResult::<StaticOption<Rc<i32>, false>, Rc<&str>, true>::new_ok(StaticOption::none());
+	/// assert_eq!(StaticResult::new_ok(StaticOption::none()), result.transpose().transpose());
+	/// ```
 	pub const fn transpose(self) -> StaticOption<StaticResult<T, E, true>, IS_SOME> {
 		let option = self.into_ok();
 		if IS_SOME {
@@ -67,11 +295,124 @@ impl<T, E, const IS_SOME: bool> StaticResult<StaticOption<T, IS_SOME>, E, true>
 }
 
 impl<T, E, const IS_SOME: bool> StaticResult<StaticOption<T, IS_SOME>, E, false> {
+	/// See [`core::result::Result::transpose`].
+	///
+	/// Return a [`StaticOption::some`] containing a [`StaticResult`] with the original error value.
+	///
+	/// Note that the `transpose` method on [`StaticResult<StaticOption<T, IS_SOME>, E, true>`] behaves differently.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// let result = StaticResult::<StaticOption<i32, true>, _, false>::new_err("error");
+	/// assert_eq!(StaticOption::some(StaticResult::new_err("error")), result.transpose());
+	/// ```
+	///
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// const RESULT: StaticResult<StaticOption<i32, false>, &'static str, false> = StaticResult::new_err("error");
+	/// const TRANSPOSED: StaticOption<StaticResult<i32, &'static str, false>, true> = RESULT.transpose();
+	/// assert_eq!(StaticOption::some(StaticResult::new_err("error")), TRANSPOSED);
+	/// ```
+	///
+	/// Transposing twice results in the original error again, which is neither leaked nor dropped twice:
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// # use std::rc::Rc;
+	/// let error = Rc::new("error");
+	///
+	/// let result = StaticResult::<StaticOption<Rc<i32>, false>, _, false>::new_err(error.clone());
+	/// let roundtrip = result.transpose().transpose();
+	/// let expected = StaticResult::<StaticOption<Rc<i32>, true>, _, false>::new_err(error.clone());
+	/// assert_eq!(expected, roundtrip);
+	/// expected.drop();
+	/// assert_eq!(2, Rc::strong_count(&error));
+	/// roundtrip.drop();
+	/// assert_eq!(1, Rc::strong_count(&error));
+	/// ```
 	pub const fn transpose(self) -> StaticOption<StaticResult<T, E, false>, true> {
 		StaticOption::some(StaticResult::new_err(self.into_err()))
 	}
 }
 
+impl<T, const IS_OK: bool> StaticResult<T, T, IS_OK> {
+	/// Return the `ok` value if `self` is `ok` and the error if `self` is `err`.
+	///
+	/// This is the equivalent of the unstable `Result::into_ok_or_err` from the standard library.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, i32, true>::new_ok(42);
+	/// assert_eq!(42, result.into_ok_or_err());
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, i32, false>::new_err(1337);
+	/// assert_eq!(1337, result.into_ok_or_err());
+	/// ```
+	pub const fn into_ok_or_err(self) -> T {
+		if IS_OK {
+			self.inner_ok()
+		} else {
+			self.inner_error()
+		}
+	}
+}
+
+impl<T, E, const IS_OK: bool> StaticResult<&T, &E, IS_OK> {
+	/// Take a [`StaticResult`] containing references and return a new [`StaticResult`] with owned copies.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// const OK: StaticResult<i32, u8, true> = StaticResult::new_ok(42);
+	/// const COPIED: StaticResult<i32, u8, true> = OK.as_ref().copied();
+	/// assert_eq!(StaticResult::new_ok(42), COPIED);
+	///
+	/// const ERR: StaticResult<i32, u8, false> = StaticResult::new_err(1);
+	/// const COPIED_ERR: StaticResult<i32, u8, false> = ERR.as_ref().copied();
+	/// assert_eq!(StaticResult::new_err(1), COPIED_ERR);
+	/// ```
+	pub const fn copied(self) -> StaticResult<T, E, IS_OK>
+	where
+		T: Copy,
+		E: Copy,
+	{
+		if IS_OK {
+			StaticResult::create_ok(*self.inner_ok())
+		} else {
+			StaticResult::create_err(*self.inner_error())
+		}
+	}
+
+	/// Take a [`StaticResult`] containing references and return a new [`StaticResult`] with owned clones.
+	///
+	/// Unlike [`StaticResult::copied`], this can't be a const fn because [`Clone::clone`] isn't const.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let ok = StaticResult::<String, String, true>::new_ok(String::from("value"));
+	/// assert_eq!(StaticResult::new_ok(String::from("value")), ok.as_ref().cloned());
+	///
+	/// let err = StaticResult::<String, String, false>::new_err(String::from("error"));
+	/// assert_eq!(StaticResult::new_err(String::from("error")), err.as_ref().cloned());
+	/// ```
+	pub fn cloned(self) -> StaticResult<T, E, IS_OK>
+	where
+		T: Clone,
+		E: Clone,
+	{
+		if IS_OK {
+			StaticResult::create_ok(self.inner_ok().clone())
+		} else {
+			StaticResult::create_err(self.inner_error().clone())
+		}
+	}
+}
+
 impl<T, E> StaticResult<T, E, false> {
 	pub const fn new_err(error: E) -> StaticResult<T, E, false> {
 		StaticResult::create_err(error)
@@ -89,6 +430,38 @@ impl<T, E> StaticResult<T, E, false> {
 		StaticResult::new_err(self.into_err())
 	}
 
+	/// Return the error of `self`, dropping `other` and `_function` without calling it.
+	///
+	/// Note that the `zip_with` method on [`StaticResult<T, E, true>`] behaves differently.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, &str, false>::new_err("first");
+	/// let other = StaticResult::<i32, &str, true>::new_ok(41);
+	/// let zipped = result.zip_with(other, |_, _| -> i32 { unreachable!() });
+	/// assert_eq!(StaticResult::new_err("first"), zipped);
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, &str, false>::new_err("first");
+	/// let other = StaticResult::<i32, &str, false>::new_err("second");
+	/// let zipped = result.zip_with(other, |_, _| -> i32 { unreachable!() });
+	/// assert_eq!(StaticResult::new_err("first"), zipped);
+	/// ```
+	pub fn zip_with<U, R, F, const IS_OK: bool>(
+		self,
+		other: StaticResult<U, E, IS_OK>,
+		_function: F,
+	) -> StaticResult<R, E, false>
+	where
+		F: FnOnce(T, U) -> R,
+	{
+		other.drop();
+		StaticResult::new_err(self.into_err())
+	}
+
 	pub fn or<F, const IS_SOME: bool>(self, res: StaticResult<T, F, IS_SOME>) -> StaticResult<T, F, IS_SOME> {
 		self.drop();
 		res
@@ -105,6 +478,46 @@ impl<T, E> StaticResult<T, E, false> {
 		self.inner_error()
 	}
 
+	/// Take out the error, same as [`StaticResult::into_err`]. Unlike `unwrap_err`, this doesn't require
+	/// `T: Debug`.
+	///
+	/// # Example
+	/// ```
+	/// # use static_option::StaticResult;
+	/// const ERR: &str = StaticResult::<(), &str, false>::new_err("error").into_inner_err();
+	/// assert_eq!("error", ERR);
+	/// ```
+	pub const fn into_inner_err(self) -> E {
+		self.inner_error()
+	}
+
+	/// Take out the error without any bounds, since a [`StaticResult<T, E, false>`] always contains one. Same as
+	/// [`StaticResult::into_inner_err`], named for symmetry with [`StaticResult::unwrap_err`].
+	///
+	/// # Example
+	/// ```
+	/// # use static_option::StaticResult;
+	/// struct NotDebug;
+	///
+	/// const ERR: &str = StaticResult::<NotDebug, &str, false>::new_err("error").unwrap_err_infallible();
+	/// assert_eq!("error", ERR);
+	/// ```
+	pub const fn unwrap_err_infallible(self) -> E {
+		self.inner_error()
+	}
+
+	/// Move the error into a [`StaticOption<E, true>`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// const OPTION: StaticOption<&str, true> = StaticResult::<i32, &str, false>::new_err("error").into_err_option();
+	/// assert_eq!(StaticOption::some("error"), OPTION);
+	/// ```
+	pub const fn into_err_option(self) -> StaticOption<E, true> {
+		StaticOption::new_some(self.inner_error())
+	}
+
 	pub fn err_ref(&self) -> &E {
 		self.as_error()
 	}
@@ -112,9 +525,211 @@ impl<T, E> StaticResult<T, E, false> {
 	pub fn err_mut(&mut self) -> &mut E {
 		self.as_error_mut()
 	}
+
+	/// Replace the current error in `self`, returning a [`StaticResult`] containing the previous error.
+	///
+	/// Note that this method only exists on [`StaticResult<T, E, false>`] because a [`StaticResult<T, E, true>`] can
+	/// never be modified to contain an error.
+	///
+	/// # Example
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let mut result = StaticResult::<i32, &'static str, false>::new_err("old");
+	/// let original = result.replace_err("new");
+	/// assert_eq!(StaticResult::new_err("old"), original);
+	/// assert_eq!(StaticResult::new_err("new"), result);
+	/// ```
+	pub fn replace_err(&mut self, mut error: E) -> StaticResult<T, E, false> {
+		swap(self.err_mut(), &mut error);
+		StaticResult::new_err(error)
+	}
+
+	/// Transpose a [`core::option::Option`] of an `err` [`StaticResult`] into a [`core::result::Result`]
+	/// of a [`core::option::Option`], turning `Some(err(error))` into `Err(error)` and `None` into `Ok(None)`.
+	///
+	/// Since it depends on the [`core::option::Option`] being `Some` or `None` whether this is `Ok` or `Err`,
+	/// this can't return a [`StaticResult`].
+	///
+	/// Note that the `transpose_option` function on [`StaticResult<T, E, true>`] behaves differently.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let option = Some(StaticResult::<i32, &'static str, false>::new_err("error"));
+	/// assert_eq!(Err("error"), StaticResult::<_, _, false>::transpose_option(option));
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let option = None::<StaticResult<i32, &'static str, false>>;
+	/// assert_eq!(Ok(None), StaticResult::<_, _, false>::transpose_option(option));
+	/// ```
+	pub fn transpose_option(option: Option<Self>) -> Result<Option<T>, E> {
+		match option {
+			Some(result) => Err(result.into_err()),
+			None => Ok(None),
+		}
+	}
+}
+
+impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK>
+where
+	T: Copy,
+	E: Copy,
+{
+	/// Like [`StaticResult::unwrap_or`], but usable in const contexts.
+	///
+	/// [`StaticResult::unwrap_or`] has to drop either `default` or the error, which isn't possible in a const fn
+	/// for arbitrary types. [`Copy`] types don't need to be dropped, so this is restricted to them.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// const OK: i32 = StaticResult::<i32, u8, true>::new_ok(42).unwrap_or_const(1337);
+	/// assert_eq!(42, OK);
+	///
+	/// const DEFAULT: i32 = StaticResult::<i32, u8, false>::new_err(0).unwrap_or_const(1337);
+	/// assert_eq!(1337, DEFAULT);
+	/// ```
+	pub const fn unwrap_or_const(self, default: T) -> T {
+		if IS_OK {
+			self.inner_ok()
+		} else {
+			default
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
+	/// Combine `self` with `other`, returning both `ok` values or every error instead of only the first one.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let ok = StaticResult::<i32, &str, true>::new_ok(1);
+	/// assert_eq!(Ok((1, 'a')), ok.merge_errs(StaticResult::<char, &str, true>::new_ok('a')));
+	///
+	/// let ok = StaticResult::<i32, &str, true>::new_ok(1);
+	/// assert_eq!(Err(vec!["second"]), ok.merge_errs(StaticResult::<char, &str, false>::new_err("second")));
+	///
+	/// let err = StaticResult::<i32, &str, false>::new_err("first");
+	/// assert_eq!(Err(vec!["first"]), err.merge_errs(StaticResult::<char, &str, true>::new_ok('a')));
+	///
+	/// let err = StaticResult::<i32, &str, false>::new_err("first");
+	/// assert_eq!(
+	/// 	Err(vec!["first", "second"]),
+	/// 	err.merge_errs(StaticResult::<char, &str, false>::new_err("second"))
+	/// );
+	/// ```
+	pub fn merge_errs<U, const OTHER_IS_OK: bool>(
+		self,
+		other: StaticResult<U, E, OTHER_IS_OK>,
+	) -> Result<(T, U), Vec<E>> {
+		match (self.into_result(), other.into_result()) {
+			(Ok(ok), Ok(other_ok)) => Ok((ok, other_ok)),
+			(Ok(_), Err(error)) | (Err(error), Ok(_)) => Err(alloc::vec![error]),
+			(Err(error), Err(other_error)) => Err(alloc::vec![error, other_error]),
+		}
+	}
+}
+
+#[cfg(feature = "nightly")]
+impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
+	/// Turn an `ok` into an `err` and an `err` into an `ok`, swapping the type parameters.
+	///
+	/// This requires the `nightly` feature because the const generic parameter is inverted.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, &'static str, true>::new_ok(42);
+	/// assert_eq!(StaticResult::<&'static str, i32, false>::new_err(42), result.swap());
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, &'static str, false>::new_err("error");
+	/// assert_eq!(StaticResult::<&'static str, i32, true>::new_ok("error"), result.swap());
+	/// ```
+	pub const fn swap(self) -> StaticResult<E, T, { !IS_OK }> {
+		if IS_OK {
+			StaticResult::create_err(self.inner_ok())
+		} else {
+			StaticResult::create_ok(self.inner_error())
+		}
+	}
+
+	/// Turn an `ok` into an `err` with the [`Default`] error and an `err` into an `ok` with the [`Default`] `ok`
+	/// value, dropping the original value.
+	///
+	/// This is useful for exercising both states in tests. It requires the `nightly` feature because the const
+	/// generic parameter is inverted.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, String, true>::new_ok(42);
+	/// assert_eq!(StaticResult::<i32, String, false>::new_err(String::new()), result.negate());
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, String, false>::new_err("error".into());
+	/// assert_eq!(StaticResult::<i32, String, true>::new_ok(0), result.negate());
+	/// ```
+	pub fn negate(self) -> StaticResult<T, E, { !IS_OK }>
+	where
+		T: Default,
+		E: Default,
+	{
+		self.drop();
+		if IS_OK {
+			StaticResult::create_err(E::default())
+		} else {
+			StaticResult::create_ok(T::default())
+		}
+	}
+
+	/// Split into the `ok` value and the error as two [`StaticOption`]s with complementary flags, so exactly one of
+	/// them contains a value.
+	///
+	/// This requires the `nightly` feature because the const generic parameter of the error half is inverted.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// let (ok, error) = StaticResult::<i32, &'static str, true>::new_ok(42).split();
+	/// assert_eq!(StaticOption::some(42), ok);
+	/// assert_eq!(StaticOption::<&'static str, false>::none(), error);
+	/// ```
+	///
+	/// ```
+	/// # use static_option::{StaticOption, StaticResult};
+	/// let (ok, error) = StaticResult::<i32, &'static str, false>::new_err("error").split();
+	/// assert_eq!(StaticOption::<i32, false>::none(), ok);
+	/// assert_eq!(StaticOption::some("error"), error);
+	/// ```
+	pub const fn split(self) -> (StaticOption<T, IS_OK>, StaticOption<E, { !IS_OK }>) {
+		if IS_OK {
+			(StaticOption::new_some(self.inner_ok()), StaticOption::new_none())
+		} else {
+			(StaticOption::new_none(), StaticOption::new_some(self.inner_error()))
+		}
+	}
 }
 
 impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
+	/// Whether this type contains an `ok` value, readable in const code without an instance.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// const _: () = assert!(StaticResult::<i32, &str, true>::IS_OK);
+	/// const _: () = assert!(!StaticResult::<i32, &str, false>::IS_OK);
+	/// ```
+	pub const IS_OK: bool = IS_OK;
+
 	pub const fn is_ok(&self) -> bool {
 		IS_OK
 	}
@@ -123,6 +738,103 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 		!IS_OK
 	}
 
+	/// Return the number of `ok` values in this [`StaticResult`], `1` if it is `ok`, `0` otherwise.
+	///
+	/// This is determined by the type alone.
+	///
+	/// # Example
+	/// ```
+	/// # use static_option::StaticResult;
+	/// assert_eq!(1, StaticResult::<i32, (), true>::new_ok(42).count());
+	/// assert_eq!(0, StaticResult::<i32, (), false>::new_err(()).count());
+	///
+	/// const RESULT: StaticResult<i32, (), true> = StaticResult::new_ok(42);
+	/// const COUNT: usize = RESULT.count();
+	/// assert_eq!(1, COUNT);
+	/// ```
+	pub const fn count(&self) -> usize {
+		IS_OK as usize
+	}
+
+	/// Return the number of `ok` values in this [`StaticResult`], which is the length of [`StaticResult::iter`].
+	///
+	/// # Example
+	/// ```
+	/// # use static_option::StaticResult;
+	/// const OK: StaticResult<i32, (), true> = StaticResult::new_ok(42);
+	/// const OK_LEN: usize = OK.ok_len();
+	/// assert_eq!(1, OK_LEN);
+	/// assert_eq!(OK.iter().count(), OK_LEN);
+	///
+	/// const ERR: StaticResult<i32, (), false> = StaticResult::new_err(());
+	/// const ERR_OK_LEN: usize = ERR.ok_len();
+	/// assert_eq!(0, ERR_OK_LEN);
+	/// ```
+	pub const fn ok_len(&self) -> usize {
+		IS_OK as usize
+	}
+
+	/// Return the number of errors in this [`StaticResult`], `1` if it is `err`, `0` otherwise.
+	///
+	/// # Example
+	/// ```
+	/// # use static_option::StaticResult;
+	/// const OK: StaticResult<i32, (), true> = StaticResult::new_ok(42);
+	/// const OK_ERR_LEN: usize = OK.err_len();
+	/// assert_eq!(0, OK_ERR_LEN);
+	///
+	/// const ERR: StaticResult<i32, (), false> = StaticResult::new_err(());
+	/// const ERR_LEN: usize = ERR.err_len();
+	/// assert_eq!(1, ERR_LEN);
+	/// ```
+	pub const fn err_len(&self) -> usize {
+		!IS_OK as usize
+	}
+
+	/// Return `true` if this [`StaticResult`] is `ok` and its value equals `other`, `false` otherwise.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, &'static str, true>::new_ok(42);
+	/// assert!(result.contains(&42));
+	/// assert!(!result.contains(&1337));
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, i32, false>::new_err(42);
+	/// assert!(!result.contains(&42));
+	/// ```
+	pub fn contains<U>(&self, other: &U) -> bool
+	where
+		U: PartialEq<T>,
+	{
+		IS_OK && other.eq(self.as_ok())
+	}
+
+	/// Return `true` if this [`StaticResult`] is `err` and its error equals `other`, `false` otherwise.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, &'static str, false>::new_err("error");
+	/// assert!(result.contains_err(&"error"));
+	/// assert!(!result.contains_err(&"other"));
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, i32, true>::new_ok(42);
+	/// assert!(!result.contains_err(&42));
+	/// ```
+	pub fn contains_err<F>(&self, other: &F) -> bool
+	where
+		F: PartialEq<E>,
+	{
+		!IS_OK && other.eq(self.as_error())
+	}
+
 	pub fn ok(self) -> StaticOption<T, IS_OK> {
 		if IS_OK {
 			StaticOption::new_some(self.inner_ok())
@@ -132,16 +844,47 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 		}
 	}
 
-	pub fn err(self) -> StaticOption<E, true> {
+	/// See [`core::result::Result::err`].
+	///
+	/// This returns a dynamic [`Option`] because the presence of the error is the inverse of `IS_OK`, which can't be
+	/// expressed as a const generic parameter on stable Rust. Use [`StaticResult::into_err_option`] to get a
+	/// [`StaticOption<E, true>`] from a [`StaticResult<T, E, false>`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// assert_eq!(None, StaticResult::<i32, &'static str, true>::new_ok(42).err());
+	/// assert_eq!(Some("error"), StaticResult::<i32, &'static str, false>::new_err("error").err());
+	/// ```
+	pub fn err(self) -> Option<E> {
 		if IS_OK {
 			self.drop();
-			StaticOption::new_none()
+			None
 		} else {
-			StaticOption::new_some(self.inner_error())
+			Some(self.inner_error())
 		}
 	}
 
-	pub fn as_ref(&self) -> StaticResult<&T, &E, IS_OK> {
+	/// See [`core::result::Result::as_ref`].
+	///
+	/// Given a reference to a [`StaticResult`], returns an owned [`StaticResult`] containing a reference
+	/// to the `ok` value or error in the referenced [`StaticResult`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// const RESULT: StaticResult<i32, &'static str, true> = StaticResult::new_ok(42);
+	/// const REFERENCE: StaticResult<&i32, &&'static str, true> = RESULT.as_ref();
+	/// assert_eq!(StaticResult::new_ok(&42), REFERENCE);
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticResult;
+	/// const RESULT: StaticResult<i32, &'static str, false> = StaticResult::new_err("error");
+	/// const REFERENCE: StaticResult<&i32, &&'static str, false> = RESULT.as_ref();
+	/// assert_eq!(StaticResult::new_err(&"error"), REFERENCE);
+	/// ```
+	pub const fn as_ref(&self) -> StaticResult<&T, &E, IS_OK> {
 		if IS_OK {
 			StaticResult::create_ok(self.as_ok())
 		} else {
@@ -149,6 +892,19 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 		}
 	}
 
+	/// See [`core::result::Result::as_mut`].
+	///
+	/// Given a mutable reference to a [`StaticResult`], returns an owned [`StaticResult`] containing a mutable
+	/// reference to the `ok` value or error in the referenced [`StaticResult`].
+	///
+	/// # Example
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let mut result = StaticResult::<i32, &'static str, true>::new_ok(42);
+	/// *result.as_mut().into_ok() = 1337;
+	/// assert_eq!(StaticResult::new_ok(1337), result);
+	/// ```
+	// NOTE: Can't be a `const fn` because mutable references in const fn require Rust 1.83
 	pub fn as_mut(&mut self) -> StaticResult<&mut T, &mut E, IS_OK> {
 		if IS_OK {
 			StaticResult::create_ok(self.as_ok_mut())
@@ -157,6 +913,17 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 		}
 	}
 
+	/// See [`core::result::Result::map_err`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, i32, true>::new_ok(42);
+	/// assert_eq!(StaticResult::new_ok(42), result.map_err(|error| error * 2));
+	///
+	/// let result = StaticResult::<i32, i32, false>::new_err(42);
+	/// assert_eq!(StaticResult::new_err(84), result.map_err(|error| error * 2));
+	/// ```
 	pub fn map_err<F, O>(self, mapper: O) -> StaticResult<T, F, IS_OK>
 	where
 		O: FnOnce(E) -> F,
@@ -190,6 +957,33 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 		}
 	}
 
+	/// Like [`StaticResult::as_deref_mut`], but dereferences the error instead of the `ok` value.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let mut result = StaticResult::<i32, String, true>::new_ok(42);
+	/// assert_eq!(StaticResult::<&mut i32, &mut str, true>::new_ok(&mut 42), result.as_deref_err_mut());
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let mut result = StaticResult::<i32, String, false>::new_err(String::from("error"));
+	/// result.as_deref_err_mut().unwrap_err().make_ascii_uppercase();
+	/// assert_eq!("ERROR", result.err_ref());
+	/// # result.drop();
+	/// ```
+	pub fn as_deref_err_mut(&mut self) -> StaticResult<&mut T, &mut <E as Deref>::Target, IS_OK>
+	where
+		E: DerefMut,
+	{
+		if IS_OK {
+			StaticResult::create_ok(self.as_ok_mut())
+		} else {
+			StaticResult::create_err(self.as_error_mut().deref_mut())
+		}
+	}
+
 	pub fn map<U, F>(self, mapper: F) -> StaticResult<U, E, IS_OK>
 	where
 		F: FnOnce(T) -> U,
@@ -201,6 +995,41 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 		}
 	}
 
+	/// Replace the `ok` value with `value`, dropping the old one, or keep the error and drop `value`.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// use std::rc::Rc;
+	///
+	/// let old = Rc::new("old");
+	/// let ok = StaticResult::<_, &str, true>::new_ok(old.clone());
+	/// assert_eq!(StaticResult::new_ok(42), ok.with_ok(42));
+	/// assert_eq!(1, Rc::strong_count(&old));
+	///
+	/// let value = Rc::new(42);
+	/// let err = StaticResult::<i32, &str, false>::new_err("error");
+	/// assert_eq!(StaticResult::new_err("error"), err.with_ok(value.clone()));
+	/// assert_eq!(1, Rc::strong_count(&value));
+	/// ```
+	pub fn with_ok<U>(self, value: U) -> StaticResult<U, E, IS_OK> {
+		if IS_OK {
+			self.drop();
+			StaticResult::create_ok(value)
+		} else {
+			drop(value);
+			StaticResult::create_err(self.inner_error())
+		}
+	}
+
+	/// See [`core::result::Result::map_or`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// assert_eq!(84, StaticResult::<i32, &str, true>::new_ok(42).map_or(0, |value| value * 2));
+	/// assert_eq!(0, StaticResult::<i32, &str, false>::new_err("error").map_or(0, |value| value * 2));
+	/// ```
 	pub fn map_or<U, F>(self, default: U, mapper: F) -> U
 	where
 		F: FnOnce(T) -> U,
@@ -213,6 +1042,50 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 		}
 	}
 
+	/// See [`core::result::Result::map_or_else`].
+	///
+	/// Exactly one of `mapper` or `default` is called, the other one is dropped without calling it. Values captured
+	/// by either closure are dropped like the captures of any other closure, but see the caveats in the
+	/// [crate documentation](crate) for captured [`StaticOption`]s and [`StaticResult`]s, which are leaked.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// # use std::rc::Rc;
+	/// let counter = Rc::new(());
+	/// let default = {
+	/// 	let counter = counter.clone();
+	/// 	move |_: ()| -> usize { panic!("{:?}", counter) }
+	/// };
+	/// let mapper = {
+	/// 	let counter = counter.clone();
+	/// 	move |value: usize| value + Rc::strong_count(&counter)
+	/// };
+	/// assert_eq!(3, Rc::strong_count(&counter));
+	///
+	/// let result = StaticResult::<usize, (), true>::new_ok(42);
+	/// assert_eq!(45, result.map_or_else(default, mapper));
+	/// assert_eq!(1, Rc::strong_count(&counter));
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticResult;
+	/// # use std::rc::Rc;
+	/// let counter = Rc::new(());
+	/// let default = {
+	/// 	let counter = counter.clone();
+	/// 	move |error: usize| error + Rc::strong_count(&counter)
+	/// };
+	/// let mapper = {
+	/// 	let counter = counter.clone();
+	/// 	move |_: ()| -> usize { panic!("{:?}", counter) }
+	/// };
+	/// assert_eq!(3, Rc::strong_count(&counter));
+	///
+	/// let result = StaticResult::<(), usize, false>::new_err(42);
+	/// assert_eq!(45, result.map_or_else(default, mapper));
+	/// assert_eq!(1, Rc::strong_count(&counter));
+	/// ```
 	pub fn map_or_else<U, D, F>(self, default: D, mapper: F) -> U
 	where
 		F: FnOnce(T) -> U,
@@ -233,6 +1106,95 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 		self.as_mut().ok().into_iter()
 	}
 
+	/// Iterate over a reference to the error, yielding nothing for an `ok` result.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let err = StaticResult::<i32, &str, false>::new_err("error");
+	/// let mut iterator = err.iter_err();
+	/// assert_eq!(1, iterator.len());
+	/// assert_eq!(Some(&"error"), iterator.next_back());
+	/// assert_eq!(None, iterator.next());
+	///
+	/// let ok = StaticResult::<i32, &str, true>::new_ok(42);
+	/// assert_eq!(0, ok.iter_err().len());
+	/// assert_eq!(None, ok.iter_err().next());
+	/// ```
+	pub fn iter_err(&self) -> Iter<&E> {
+		Iter::new(self.as_result().err())
+	}
+
+	/// Iterate over a mutable reference to the error, yielding nothing for an `ok` result.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let mut err = StaticResult::<i32, i32, false>::new_err(41);
+	/// for error in err.iter_err_mut() {
+	/// 	*error += 1;
+	/// }
+	/// assert_eq!(StaticResult::new_err(42), err);
+	/// ```
+	pub fn iter_err_mut(&mut self) -> Iter<&mut E> {
+		Iter::new(self.as_mut_result().err())
+	}
+
+	/// Turn `self` into an iterator over the error, yielding nothing for an `ok` result.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let err = StaticResult::<i32, &str, false>::new_err("error");
+	/// assert_eq!(Some("error"), err.into_iter_err().rev().next());
+	///
+	/// let ok = StaticResult::<i32, &str, true>::new_ok(42);
+	/// assert_eq!(None, ok.into_iter_err().rev().next());
+	/// ```
+	///
+	/// The iterator is fused, so it keeps returning [`None`] once it is exhausted:
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let mut iterator = StaticResult::<i32, &str, false>::new_err("error").into_iter_err().fuse();
+	/// assert_eq!(Some("error"), iterator.next());
+	/// assert_eq!(None, iterator.next());
+	/// assert_eq!(None, iterator.next());
+	/// ```
+	///
+	/// The `ok` value of an `ok` result is dropped:
+	/// ```
+	/// # use static_option::StaticResult;
+	/// use std::rc::Rc;
+	///
+	/// let counter = Rc::new(());
+	/// let ok = StaticResult::<Rc<()>, &str, true>::new_ok(counter.clone());
+	/// assert_eq!(2, Rc::strong_count(&counter));
+	/// assert_eq!(0, ok.into_iter_err().count());
+	/// assert_eq!(1, Rc::strong_count(&counter));
+	///
+	/// let err = StaticResult::<Rc<()>, &str, false>::new_err("error");
+	/// assert_eq!(vec!["error"], err.into_iter_err().collect::<Vec<_>>());
+	/// ```
+	pub fn into_iter_err(self) -> Iter<E> {
+		Iter::new(self.into_result().err())
+	}
+
+	/// Turn `self` into an iterator over the error, alias of [`StaticResult::into_iter_err`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let results = ["first", "second"].map(StaticResult::<i32, &str, false>::new_err);
+	/// let errors: Vec<&str> = results.into_iter().flat_map(StaticResult::into_err_iter).collect();
+	/// assert_eq!(vec!["first", "second"], errors);
+	///
+	/// let ok = StaticResult::<i32, &str, true>::new_ok(42);
+	/// assert_eq!(0, ok.into_err_iter().count());
+	/// ```
+	pub fn into_err_iter(self) -> Iter<E> {
+		self.into_iter_err()
+	}
+
 	pub fn unwrap_or(self, default: T) -> T {
 		if IS_OK {
 			self.inner_ok()
@@ -253,6 +1215,53 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 		}
 	}
 
+	/// Like [`StaticResult::unwrap_or_else`], but `default` doesn't take the error, which is dropped instead.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, String, true>::new_ok(42);
+	/// assert_eq!(42, result.unwrap_or_else_default(|| 1337));
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticResult;
+	/// # use std::rc::Rc;
+	/// let error = Rc::new("error");
+	/// let result = StaticResult::<i32, _, false>::new_err(error.clone());
+	/// assert_eq!(1337, result.unwrap_or_else_default(|| 1337));
+	/// assert_eq!(1, Rc::strong_count(&error));
+	/// ```
+	pub fn unwrap_or_else_default<F>(self, default: F) -> T
+	where
+		F: FnOnce() -> T,
+	{
+		if IS_OK {
+			self.inner_ok()
+		} else {
+			self.drop();
+			default()
+		}
+	}
+
+	/// See [`core::result::Result::expect`].
+	///
+	/// # Panics
+	/// Panics with `message` if `self` is a [`StaticResult<T, E, false>`]. Unlike
+	/// [`core::result::Result::expect`], the error isn't included in the panic message.
+	///
+	/// Like [`StaticOption::expect`], this goes through [`core::fmt::Arguments`] because `message` is only known at
+	/// runtime. Use [`StaticResult::unwrap`] if no formatting code must be pulled in.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// assert_eq!(42, StaticResult::<u32, (), true>::new_ok(42).expect("no value"));
+	///
+	/// let payload =
+	/// 	std::panic::catch_unwind(|| StaticResult::<u32, (), false>::new_err(()).expect("no value")).unwrap_err();
+	/// assert_eq!(Some(&String::from("no value")), payload.downcast_ref::<String>());
+	/// ```
 	pub fn expect(self, message: &str) -> T
 	where
 		E: Debug,
@@ -266,6 +1275,22 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 		}
 	}
 
+	/// See [`core::result::Result::unwrap`].
+	///
+	/// Unlike [`core::result::Result::unwrap`], the panic message doesn't include the error, it is the same for every
+	/// `T` and `E`, so it doesn't need to be formatted or duplicated per type.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// assert_eq!(42, StaticResult::<u32, (), true>::new_ok(42).unwrap());
+	///
+	/// let payload = std::panic::catch_unwind(|| StaticResult::<u32, (), false>::new_err(()).unwrap()).unwrap_err();
+	/// assert_eq!(
+	/// 	Some(&"called `unwrap()` on a `StaticResult::err` value"),
+	/// 	payload.downcast_ref::<&'static str>()
+	/// );
+	/// ```
 	pub fn unwrap(self) -> T
 	where
 		E: Debug,
@@ -274,10 +1299,25 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 			self.inner_ok()
 		} else {
 			self.drop();
-			panic!("called `unwrap()` on {}", type_name::<Self>())
+			panic!("called `unwrap()` on a `StaticResult::err` value")
 		}
 	}
 
+	/// See [`core::result::Result::expect_err`].
+	///
+	/// # Panics
+	/// Panics with `message` if `self` is a [`StaticResult<T, E, true>`], see [`StaticResult::expect`] for the
+	/// formatting tradeoff.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// assert_eq!(42, StaticResult::<(), u32, false>::new_err(42).expect_err("no error"));
+	///
+	/// let payload =
+	/// 	std::panic::catch_unwind(|| StaticResult::<u32, (), true>::new_ok(42).expect_err("no error")).unwrap_err();
+	/// assert_eq!(Some(&String::from("no error")), payload.downcast_ref::<String>());
+	/// ```
 	pub fn expect_err(self, message: &str) -> E
 	where
 		T: Debug,
@@ -291,13 +1331,29 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 		}
 	}
 
+	/// See [`core::result::Result::unwrap_err`].
+	///
+	/// Unlike [`core::result::Result::unwrap_err`], the panic message doesn't include the `ok` value, it is the same
+	/// for every `T` and `E`, so it doesn't need to be formatted or duplicated per type.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// assert_eq!(42, StaticResult::<(), u32, false>::new_err(42).unwrap_err());
+	///
+	/// let payload = std::panic::catch_unwind(|| StaticResult::<u32, (), true>::new_ok(42).unwrap_err()).unwrap_err();
+	/// assert_eq!(
+	/// 	Some(&"called `unwrap_err()` on a `StaticResult::ok` value"),
+	/// 	payload.downcast_ref::<&'static str>()
+	/// );
+	/// ```
 	pub fn unwrap_err(self) -> E
 	where
 		T: Debug,
 	{
 		if IS_OK {
 			self.drop();
-			panic!("called `unwrap_err()` on {}", type_name::<Self>())
+			panic!("called `unwrap_err()` on a `StaticResult::ok` value")
 		} else {
 			self.inner_error()
 		}
@@ -315,6 +1371,33 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 		}
 	}
 
+	/// Return an `ok` [`StaticResult`] containing the `ok` value if `self` is `ok`, or `T::default()` if `self` is `err`,
+	/// dropping the error.
+	///
+	/// In contrast to [`StaticResult::unwrap_or_default`], the value stays wrapped inside a [`StaticResult`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let result = StaticResult::<i32, &'static str, true>::new_ok(42);
+	/// assert_eq!(StaticResult::new_ok(42), result.or_default());
+	/// ```
+	///
+	/// ```
+	/// # use static_option::StaticResult;
+	/// # use std::rc::Rc;
+	/// let error = Rc::new("error");
+	/// let result = StaticResult::<i32, _, false>::new_err(error.clone());
+	/// assert_eq!(StaticResult::<_, Rc<&str>, true>::new_ok(0), result.or_default());
+	/// assert_eq!(1, Rc::strong_count(&error));
+	/// ```
+	pub fn or_default(self) -> StaticResult<T, E, true>
+	where
+		T: Default,
+	{
+		StaticResult::new_ok(self.unwrap_or_default())
+	}
+
 	pub fn drop(mut self) {
 		if IS_OK {
 			// SAFETY: StaticResult<T, E, true> can only be constructed with ok value inside (tracked by the true)
@@ -335,7 +1418,91 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 		}
 	}
 
-	pub fn as_result(&self) -> Result<&T, &E> {
+	/// Convert into a [`Result`] with the `ok` value as success, alias of [`StaticResult::into_result`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// assert_eq!(Ok(42), StaticResult::<i32, &str, true>::new_ok(42).try_into_ok());
+	/// assert_eq!(Err("error"), StaticResult::<i32, &str, false>::new_err("error").try_into_ok());
+	/// ```
+	pub const fn try_into_ok(self) -> Result<T, E> {
+		self.into_result()
+	}
+
+	/// Convert into a [`Result`] with the error as success and the `ok` value as error.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// assert_eq!(Err(42), StaticResult::<i32, &str, true>::new_ok(42).try_into_err());
+	/// assert_eq!(Ok("error"), StaticResult::<i32, &str, false>::new_err("error").try_into_err());
+	/// ```
+	pub const fn try_into_err(self) -> Result<E, T> {
+		if IS_OK {
+			Err(self.inner_ok())
+		} else {
+			Ok(self.inner_error())
+		}
+	}
+
+	/// Call `function` with the `ok` value and return its [`Result`], or return the error without calling
+	/// `function`.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let ok = StaticResult::<&str, String, true>::new_ok("42");
+	/// assert_eq!(Ok(42), ok.and_then_ok(|text| text.parse::<i32>().map_err(|error| error.to_string())));
+	///
+	/// let ok = StaticResult::<&str, String, true>::new_ok("text");
+	/// assert!(ok.and_then_ok(|text| text.parse::<i32>().map_err(|error| error.to_string())).is_err());
+	///
+	/// let err = StaticResult::<&str, String, false>::new_err("error".into());
+	/// assert_eq!(Err(String::from("error")), err.and_then_ok(|_| -> Result<i32, String> { unreachable!() }));
+	/// ```
+	pub fn and_then_ok<U, F>(self, function: F) -> Result<U, E>
+	where
+		F: FnOnce(T) -> Result<U, E>,
+	{
+		self.into_result().and_then(function)
+	}
+
+	/// Split into the `ok` value and the error as two [`Option`]s, exactly one of which contains a value.
+	///
+	/// This is the stable counterpart of the nightly `split` method, which returns [`StaticOption`]s instead.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let (ok, error) = StaticResult::<i32, &str, true>::new_ok(42).split_dyn();
+	/// assert_eq!((Some(42), None), (ok, error));
+	///
+	/// let (ok, error) = StaticResult::<i32, &str, false>::new_err("error").split_dyn();
+	/// assert_eq!((None, Some("error")), (ok, error));
+	/// ```
+	pub const fn split_dyn(self) -> (Option<T>, Option<E>) {
+		if IS_OK {
+			(Some(self.inner_ok()), None)
+		} else {
+			(None, Some(self.inner_error()))
+		}
+	}
+
+	/// Borrow the `ok` value or the error as a [`Result`].
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// const OK: StaticResult<i32, &str, true> = StaticResult::new_ok(42);
+	/// const OK_REFERENCE: Result<&i32, &&str> = OK.as_result();
+	/// assert_eq!(Ok(&42), OK_REFERENCE);
+	///
+	/// const ERR: StaticResult<i32, &str, false> = StaticResult::new_err("error");
+	/// const ERR_REFERENCE: Result<&i32, &&str> = ERR.as_result();
+	/// assert_eq!(Err(&"error"), ERR_REFERENCE);
+	/// ```
+	pub const fn as_result(&self) -> Result<&T, &E> {
 		if IS_OK {
 			Ok(self.as_ok())
 		} else {
@@ -343,6 +1510,44 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 		}
 	}
 
+	/// Look at the `ok` value without consuming `self`.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// const OK: StaticResult<i32, &str, true> = StaticResult::new_ok(42);
+	/// const PEEKED: Option<&i32> = OK.peek_ok();
+	/// assert_eq!(Some(&42), PEEKED);
+	///
+	/// assert_eq!(None, StaticResult::<i32, &str, false>::new_err("error").peek_ok());
+	/// ```
+	pub const fn peek_ok(&self) -> Option<&T> {
+		if IS_OK {
+			Some(self.as_ok())
+		} else {
+			None
+		}
+	}
+
+	/// Look at the error without consuming `self`.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// const ERR: StaticResult<i32, &str, false> = StaticResult::new_err("error");
+	/// const PEEKED: Option<&&str> = ERR.peek_err();
+	/// assert_eq!(Some(&"error"), PEEKED);
+	///
+	/// assert_eq!(None, StaticResult::<i32, &str, true>::new_ok(42).peek_err());
+	/// ```
+	pub const fn peek_err(&self) -> Option<&E> {
+		if IS_OK {
+			None
+		} else {
+			Some(self.as_error())
+		}
+	}
+
 	pub fn as_mut_result(&mut self) -> Result<&mut T, &mut E> {
 		if IS_OK {
 			Ok(self.as_ok_mut())
@@ -351,6 +1556,30 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 		}
 	}
 
+	/// Clone the `ok` value or the error into a [`Result`] without consuming `self`.
+	///
+	/// # Examples
+	/// ```
+	/// # use static_option::StaticResult;
+	/// let ok = StaticResult::<String, String, true>::new_ok(String::from("value"));
+	/// assert_eq!(Ok(String::from("value")), ok.to_result());
+	/// assert_eq!(StaticResult::new_ok(String::from("value")), ok);
+	///
+	/// let err = StaticResult::<String, String, false>::new_err(String::from("error"));
+	/// assert_eq!(Err(String::from("error")), err.to_result());
+	/// assert_eq!(StaticResult::new_err(String::from("error")), err);
+	/// ```
+	pub fn to_result(&self) -> Result<T, E>
+	where
+		T: Clone,
+		E: Clone,
+	{
+		match self.as_result() {
+			Ok(ok) => Ok(ok.clone()),
+			Err(error) => Err(error.clone()),
+		}
+	}
+
 	// Equivalent to `new_ok` but doesn't require explicit `true` as type parameter.
 	#[inline(always)]
 	pub(crate) const fn create_ok(ok: T) -> Self {
@@ -382,11 +1611,13 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 
 	// Equivalent to `ok_ref` but doesn't require explicit `true` as type parameter.
 	#[inline(always)]
-	pub(crate) fn as_ok(&self) -> &T {
+	pub(crate) const fn as_ok(&self) -> &T {
 		// SAFETY: StaticResult<T, E, true> can only be constructed with a value inside (tracked by the `true`)
-		// and the assert ensures that the `ok` union field is only accessed when it is initialized
+		// and the assert ensures that the `ok` union field is only accessed when it is initialized.
+		// The pointer cast is used instead of `Deref`, which isn't const, and is sound because `ManuallyDrop<T>`
+		// is `#[repr(transparent)]`.
 		assert!(IS_OK); // gets optimized away
-		unsafe { &self.ok }
+		unsafe { &*(&self.ok as *const ManuallyDrop<T> as *const T) }
 	}
 
 	// Equivalent to `ok_mut` but doesn't require explicit `true` as type parameter.
@@ -409,11 +1640,12 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 
 	// Equivalent to `err_ref` but doesn't require explicit `false` as type parameter.
 	#[inline(always)]
-	pub(crate) fn as_error(&self) -> &E {
+	pub(crate) const fn as_error(&self) -> &E {
 		// SAFETY: StaticResult<T, E, false> can only be constructed with a value inside (tracked by the `false`)
-		// and the assert ensures that the `error` union field is only accessed when it is initialized
+		// and the assert ensures that the `error` union field is only accessed when it is initialized.
+		// See `as_ok` for the pointer cast.
 		assert!(!IS_OK);
-		unsafe { &self.error }
+		unsafe { &*(&self.error as *const ManuallyDrop<E> as *const E) }
 	}
 
 	// Equivalent to `err_mut` but doesn't require explicit `false` as type parameter.
@@ -426,6 +1658,45 @@ impl<T, E, const IS_OK: bool> StaticResult<T, E, IS_OK> {
 	}
 }
 
+/// Create an `ok` [`StaticResult<T, E, true>`] containing `T::default()`.
+///
+/// Note that the [`Default`] implementation for [`StaticResult<T, E, false>`] uses `E::default()` instead,
+/// because the `false` type parameter statically requires an error value.
+///
+/// # Example
+/// ```
+/// # use static_option::StaticResult;
+/// assert_eq!(StaticResult::new_ok(0), StaticResult::<i32, &'static str, true>::default());
+/// ```
+impl<T, E> Default for StaticResult<T, E, true>
+where
+	T: Default,
+{
+	fn default() -> Self {
+		StaticResult::new_ok(T::default())
+	}
+}
+
+/// Create an `err` [`StaticResult<T, E, false>`] containing `E::default()`.
+///
+/// Note that the [`Default`] implementation for [`StaticResult<T, E, true>`] uses `T::default()` instead,
+/// because the `true` type parameter statically requires an `ok` value.
+///
+/// # Example
+/// ```
+/// # use static_option::StaticResult;
+/// let result = StaticResult::<i32, String, false>::default();
+/// assert_eq!(StaticResult::new_err(String::new()), result);
+/// ```
+impl<T, E> Default for StaticResult<T, E, false>
+where
+	E: Default,
+{
+	fn default() -> Self {
+		StaticResult::new_err(E::default())
+	}
+}
+
 impl<T, E, const IS_OK: bool> Clone for StaticResult<T, E, IS_OK>
 where
 	T: Clone,
@@ -440,17 +1711,68 @@ where
 	}
 }
 
+/// Collects the `ok` values like [`Result`] does, stopping at the first error.
+///
+/// The result is a dynamic [`Result`] because an empty iterator of [`StaticResult<T, E, false>`] collects into an
+/// `ok`, so the state of the result isn't known statically.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticResult;
+/// let results = (1..=3).map(StaticResult::<i32, String, true>::new_ok);
+/// assert_eq!(Ok(vec![1, 2, 3]), results.collect::<Result<Vec<i32>, String>>());
+///
+/// let mut consumed = 0;
+/// let results = ["first", "second"].into_iter().map(|error| {
+/// 	consumed += 1;
+/// 	StaticResult::<i32, String, false>::new_err(error.into())
+/// });
+/// assert_eq!(Err(String::from("first")), results.collect::<Result<Vec<i32>, String>>());
+/// assert_eq!(1, consumed);
+///
+/// let results = std::iter::empty::<StaticResult<i32, String, false>>();
+/// assert_eq!(Ok(Vec::new()), results.collect::<Result<Vec<i32>, String>>());
+/// ```
+impl<T, E, C, const IS_OK: bool> FromIterator<StaticResult<T, E, IS_OK>> for Result<C, E>
+where
+	C: FromIterator<T>,
+{
+	fn from_iter<I>(iter: I) -> Self
+	where
+		I: IntoIterator<Item = StaticResult<T, E, IS_OK>>,
+	{
+		iter.into_iter().map(StaticResult::into_result).collect()
+	}
+}
+
+/// Hashes the same way as the equivalent [`Result`], including the discriminant, so that an `ok` and an `err`
+/// with the same value produce different hashes.
+///
+/// # Example
+/// ```
+/// # use static_option::StaticResult;
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::{Hash, Hasher};
+///
+/// fn hash(value: impl Hash) -> u64 {
+/// 	let mut hasher = DefaultHasher::new();
+/// 	value.hash(&mut hasher);
+/// 	hasher.finish()
+/// }
+///
+/// let ok = StaticResult::<u8, u8, true>::new_ok(5);
+/// let err = StaticResult::<u8, u8, false>::new_err(5);
+/// assert_ne!(hash(ok), hash(err));
+/// assert_eq!(hash(Ok::<u8, u8>(5)), hash(ok));
+/// assert_eq!(hash(Err::<u8, u8>(5)), hash(err));
+/// ```
 impl<T, E, const IS_OK: bool> Hash for StaticResult<T, E, IS_OK>
 where
 	T: Hash,
 	E: Hash,
 {
 	fn hash<H: Hasher>(&self, state: &mut H) {
-		if IS_OK {
-			self.as_ok().hash(state)
-		} else {
-			self.as_error().hash(state)
-		}
+		self.as_result().hash(state)
 	}
 }
 
@@ -513,6 +1835,22 @@ impl<T, E, const IS_OK: bool> From<StaticResult<T, E, IS_OK>> for Result<T, E> {
 	}
 }
 
+/// Formats like a tuple struct named `StaticResult::ok` or `StaticResult::err`, also in the alternate `{:#?}` form.
+///
+/// # Examples
+/// ```
+/// # use static_option::{StaticOption, StaticResult};
+/// let ok = StaticResult::<_, &str, true>::new_ok(StaticOption::some(1));
+/// assert_eq!("StaticResult::ok(StaticOption::some(1))", format!("{:?}", ok));
+/// assert_eq!(
+/// 	"StaticResult::ok(\n    StaticOption::some(\n        1,\n    ),\n)",
+/// 	format!("{:#?}", ok)
+/// );
+///
+/// let err = StaticResult::<i32, &str, false>::new_err("error");
+/// assert_eq!("StaticResult::err(\"error\")", format!("{:?}", err));
+/// assert_eq!("StaticResult::err(\n    \"error\",\n)", format!("{:#?}", err));
+/// ```
 impl<T, E, const IS_OK: bool> Debug for StaticResult<T, E, IS_OK>
 where
 	T: Debug,
@@ -523,9 +1861,105 @@ where
 			formatter.debug_tuple("StaticResult::ok").field(self.as_ok()).finish()
 		} else {
 			formatter
-				.debug_tuple("StaticOption::err")
+				.debug_tuple("StaticResult::err")
 				.field(self.as_error())
 				.finish()
 		}
 	}
 }
+
+/// Display the error of a [`StaticResult<T, E, false>`].
+///
+/// # Example
+/// ```
+/// # use static_option::StaticResult;
+/// let result = StaticResult::<i32, &'static str, false>::new_err("error");
+/// assert_eq!("error", result.to_string());
+/// ```
+impl<T, E> Display for StaticResult<T, E, false>
+where
+	E: Display,
+{
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+		self.err_ref().fmt(formatter)
+	}
+}
+
+/// A [`StaticResult<T, E, false>`] always contains an error, so it can be used as an error itself,
+/// forwarding to the contained error.
+///
+/// # Example
+/// ```
+/// # use static_option::StaticResult;
+/// use std::error::Error;
+/// use std::fmt::{Display, Formatter};
+///
+/// #[derive(Debug)]
+/// struct MyError;
+///
+/// impl Display for MyError {
+/// 	fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+/// 		formatter.write_str("my error")
+/// 	}
+/// }
+///
+/// impl Error for MyError {}
+///
+/// let error: Box<dyn Error> = Box::new(StaticResult::<(), _, false>::new_err(MyError));
+/// assert_eq!("my error", error.to_string());
+/// assert!(error.source().is_none());
+///
+/// fn fails() -> Result<(), Box<dyn Error>> {
+/// 	Err(StaticResult::<(), _, false>::new_err(MyError))?
+/// }
+/// assert_eq!("my error", fails().unwrap_err().to_string());
+/// ```
+#[cfg(feature = "std")]
+impl<T, E> std::error::Error for StaticResult<T, E, false>
+where
+	T: Debug,
+	E: std::error::Error,
+{
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		self.err_ref().source()
+	}
+
+	#[allow(deprecated)]
+	fn description(&self) -> &str {
+		self.err_ref().description()
+	}
+}
+
+/// Allows returning a [`StaticResult`] from `main`, behaving the same as the equivalent [`Result`].
+/// An error is printed to stderr using its [`Debug`] implementation and results in a failure exit code.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticResult;
+/// #[derive(Debug)]
+/// struct MyError;
+///
+/// fn main() -> StaticResult<(), MyError, true> {
+/// 	StaticResult::new_ok(())
+/// }
+/// ```
+///
+/// ```should_panic
+/// # use static_option::StaticResult;
+/// #[derive(Debug)]
+/// struct MyError;
+///
+/// fn main() -> StaticResult<(), MyError, false> {
+/// 	StaticResult::new_err(MyError)
+/// }
+/// ```
+#[cfg(feature = "std")]
+impl<T, E, const IS_OK: bool> std::process::Termination for StaticResult<T, E, IS_OK>
+where
+	T: std::process::Termination,
+	E: Debug,
+{
+	fn report(self) -> std::process::ExitCode {
+		self.into_result().report()
+	}
+}
@@ -0,0 +1,113 @@
+use crate::{StaticOption, StaticResult};
+use scale_info::build::{Fields, Variants};
+use scale_info::{type_params, Path, Type, TypeInfo};
+
+/// Describes the same shape as [`Option`], but only with the variant that is statically possible.
+/// The variant keeps the index it has in [`Option`]. Since the two shapes differ, the path is `StaticOptionSome` for a
+/// [`StaticOption<T, true>`] and `StaticOptionNone` for a [`StaticOption<T, false>`], so they don't collide in code
+/// generators that look up types by path.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticOption;
+/// use scale_info::{TypeDef, TypeInfo};
+///
+/// let info = StaticOption::<u32, true>::type_info();
+/// assert_eq!(&["static_option", "StaticOptionSome"], info.path.segments.as_slice());
+/// let TypeDef::Variant(variants) = info.type_def else { panic!("not an enum") };
+/// assert_eq!(1, variants.variants.len());
+/// assert_eq!("Some", variants.variants[0].name);
+/// assert_eq!(1, variants.variants[0].index);
+///
+/// let info = StaticOption::<u32, false>::type_info();
+/// assert_eq!(&["static_option", "StaticOptionNone"], info.path.segments.as_slice());
+/// let TypeDef::Variant(variants) = info.type_def else { panic!("not an enum") };
+/// assert_eq!(1, variants.variants.len());
+/// assert_eq!("None", variants.variants[0].name);
+/// assert_eq!(0, variants.variants[0].index);
+///
+/// assert_ne!(StaticOption::<u32, true>::type_info(), StaticOption::<u32, false>::type_info());
+/// ```
+impl<T, const IS_SOME: bool> TypeInfo for StaticOption<T, IS_SOME>
+where
+	T: TypeInfo + 'static,
+{
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		let variants = if IS_SOME {
+			Variants::new().variant("Some", |variant| {
+				variant
+					.index(1)
+					.fields(Fields::unnamed().field(|field| field.ty::<T>()))
+			})
+		} else {
+			Variants::new().variant("None", |variant| variant.index(0))
+		};
+
+		let name = if IS_SOME {
+			"StaticOptionSome"
+		} else {
+			"StaticOptionNone"
+		};
+		Type::builder()
+			.path(Path::new(name, "static_option"))
+			.type_params(type_params![T])
+			.variant(variants)
+	}
+}
+
+/// Describes the same shape as [`Result`], but only with the variant that is statically possible.
+/// The variant keeps the index it has in [`Result`]. Since the two shapes differ, the path is `StaticResultOk` for a
+/// [`StaticResult<T, E, true>`] and `StaticResultErr` for a [`StaticResult<T, E, false>`].
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticResult;
+/// use scale_info::{TypeDef, TypeInfo};
+///
+/// let info = StaticResult::<u32, u8, true>::type_info();
+/// assert_eq!(&["static_option", "StaticResultOk"], info.path.segments.as_slice());
+/// let TypeDef::Variant(variants) = info.type_def else { panic!("not an enum") };
+/// assert_eq!(1, variants.variants.len());
+/// assert_eq!("Ok", variants.variants[0].name);
+/// assert_eq!(0, variants.variants[0].index);
+///
+/// let info = StaticResult::<u32, u8, false>::type_info();
+/// assert_eq!(&["static_option", "StaticResultErr"], info.path.segments.as_slice());
+/// let TypeDef::Variant(variants) = info.type_def else { panic!("not an enum") };
+/// assert_eq!(1, variants.variants.len());
+/// assert_eq!("Err", variants.variants[0].name);
+/// assert_eq!(1, variants.variants[0].index);
+///
+/// assert_ne!(StaticResult::<u32, u8, true>::type_info(), StaticResult::<u32, u8, false>::type_info());
+/// ```
+impl<T, E, const IS_OK: bool> TypeInfo for StaticResult<T, E, IS_OK>
+where
+	T: TypeInfo + 'static,
+	E: TypeInfo + 'static,
+{
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		let variants = if IS_OK {
+			Variants::new().variant("Ok", |variant| {
+				variant
+					.index(0)
+					.fields(Fields::unnamed().field(|field| field.ty::<T>()))
+			})
+		} else {
+			Variants::new().variant("Err", |variant| {
+				variant
+					.index(1)
+					.fields(Fields::unnamed().field(|field| field.ty::<E>()))
+			})
+		};
+
+		let name = if IS_OK { "StaticResultOk" } else { "StaticResultErr" };
+		Type::builder()
+			.path(Path::new(name, "static_option"))
+			.type_params(type_params!(T, E))
+			.variant(variants)
+	}
+}
@@ -0,0 +1,104 @@
+use crate::{StaticOption, StaticResult};
+use alloc::borrow::Cow;
+use alloc::format;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+/// Uses the same schema as `T` if present and `null` otherwise.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticOption;
+/// assert_eq!(schemars::schema_for!(u32), schemars::schema_for!(StaticOption<u32, true>));
+/// assert_eq!(schemars::schema_for!(()), schemars::schema_for!(StaticOption<u32, false>));
+/// ```
+impl<T, const IS_SOME: bool> JsonSchema for StaticOption<T, IS_SOME>
+where
+	T: JsonSchema,
+{
+	fn inline_schema() -> bool {
+		!IS_SOME || T::inline_schema()
+	}
+
+	fn schema_name() -> Cow<'static, str> {
+		if IS_SOME {
+			T::schema_name()
+		} else {
+			"null".into()
+		}
+	}
+
+	fn schema_id() -> Cow<'static, str> {
+		if IS_SOME {
+			T::schema_id()
+		} else {
+			"null".into()
+		}
+	}
+
+	fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+		if IS_SOME {
+			T::json_schema(generator)
+		} else {
+			json_schema!({
+				"type": "null"
+			})
+		}
+	}
+}
+
+/// Uses an object with either an `Ok` or an `Err` property, like [`Result`], but only with the variant that is present.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticResult;
+/// let schema = schemars::schema_for!(StaticResult<u32, (), true>);
+/// assert_eq!(r#"["Ok"]"#, schema.get("required").unwrap().to_string());
+///
+/// let schema = schemars::schema_for!(StaticResult<(), u32, false>);
+/// assert_eq!(r#"["Err"]"#, schema.get("required").unwrap().to_string());
+/// ```
+impl<T, E, const IS_OK: bool> JsonSchema for StaticResult<T, E, IS_OK>
+where
+	T: JsonSchema,
+	E: JsonSchema,
+{
+	fn inline_schema() -> bool {
+		true
+	}
+
+	fn schema_name() -> Cow<'static, str> {
+		if IS_OK {
+			format!("StaticResult_Ok_{}", T::schema_name()).into()
+		} else {
+			format!("StaticResult_Err_{}", E::schema_name()).into()
+		}
+	}
+
+	fn schema_id() -> Cow<'static, str> {
+		if IS_OK {
+			format!("static_option::StaticResult<{}, Ok>", T::schema_id()).into()
+		} else {
+			format!("static_option::StaticResult<{}, Err>", E::schema_id()).into()
+		}
+	}
+
+	fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+		if IS_OK {
+			json_schema!({
+				"type": "object",
+				"properties": {
+					"Ok": generator.subschema_for::<T>()
+				},
+				"required": ["Ok"]
+			})
+		} else {
+			json_schema!({
+				"type": "object",
+				"properties": {
+					"Err": generator.subschema_for::<E>()
+				},
+				"required": ["Err"]
+			})
+		}
+	}
+}
@@ -0,0 +1,215 @@
+//! The serde implementations use the same representation as [`Option`] and [`Result`]. The examples check this with
+//! `postcard` and fixed size buffers, so no allocator is needed.
+//!
+//! # Examples
+//! ```
+//! # use static_option::StaticOption;
+//! let mut buffer = [0u8; 8];
+//! let mut expected = [0u8; 8];
+//!
+//! let bytes = postcard::to_slice(&StaticOption::some(300u32), &mut buffer).unwrap();
+//! assert_eq!(postcard::to_slice(&Some(300u32), &mut expected).unwrap(), bytes);
+//! assert_eq!(StaticOption::some(300u32), postcard::from_bytes(bytes).unwrap());
+//!
+//! let bytes = postcard::to_slice(&StaticOption::<u32, false>::none(), &mut buffer).unwrap();
+//! assert_eq!(postcard::to_slice(&None::<u32>, &mut expected).unwrap(), bytes);
+//! assert_eq!(StaticOption::<u32, false>::none(), postcard::from_bytes(bytes).unwrap());
+//! ```
+//!
+//! ```
+//! # use static_option::StaticResult;
+//! let mut buffer = [0u8; 8];
+//! let mut expected = [0u8; 8];
+//!
+//! let result = StaticResult::<u32, i8, true>::new_ok(300);
+//! let bytes = postcard::to_slice(&result, &mut buffer).unwrap();
+//! assert_eq!(postcard::to_slice(&Ok::<u32, i8>(300), &mut expected).unwrap(), bytes);
+//! assert_eq!(result, postcard::from_bytes(bytes).unwrap());
+//!
+//! let result = StaticResult::<u32, i8, false>::new_err(-1);
+//! let bytes = postcard::to_slice(&result, &mut buffer).unwrap();
+//! assert_eq!(postcard::to_slice(&Err::<u32, i8>(-1), &mut expected).unwrap(), bytes);
+//! assert_eq!(result, postcard::from_bytes(bytes).unwrap());
+//! ```
+//!
+//! Decoding fails cleanly if the presence or variant doesn't match the type:
+//! ```
+//! # use static_option::{StaticOption, StaticResult};
+//! let mut buffer = [0u8; 8];
+//!
+//! let bytes = postcard::to_slice(&StaticOption::some(42u32), &mut buffer).unwrap();
+//! assert!(postcard::from_bytes::<StaticOption<u32, false>>(bytes).is_err());
+//!
+//! let bytes = postcard::to_slice(&StaticOption::<u32, false>::none(), &mut buffer).unwrap();
+//! assert!(postcard::from_bytes::<StaticOption<u32, true>>(bytes).is_err());
+//!
+//! let bytes = postcard::to_slice(&StaticResult::<u32, u32, true>::new_ok(42), &mut buffer).unwrap();
+//! assert!(postcard::from_bytes::<StaticResult<u32, u32, false>>(bytes).is_err());
+//! ```
+
+use crate::{StaticOption, StaticResult};
+use core::fmt::{self, Formatter};
+use core::marker::PhantomData;
+use serde::de::{self, Deserialize, Deserializer, EnumAccess, Unexpected, VariantAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+/// Uses the same representation as [`Option`].
+impl<T, const IS_SOME: bool> Serialize for StaticOption<T, IS_SOME>
+where
+	T: Serialize,
+{
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		match self.as_option() {
+			Some(value) => serializer.serialize_some(value),
+			None => serializer.serialize_none(),
+		}
+	}
+}
+
+/// Deserializing fails if a value is present in a [`StaticOption<T, false>`] or missing in a
+/// [`StaticOption<T, true>`].
+impl<'de, T, const IS_SOME: bool> Deserialize<'de> for StaticOption<T, IS_SOME>
+where
+	T: Deserialize<'de>,
+{
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_option(OptionVisitor(PhantomData))
+	}
+}
+
+struct OptionVisitor<T, const IS_SOME: bool>(PhantomData<T>);
+
+impl<'de, T, const IS_SOME: bool> Visitor<'de> for OptionVisitor<T, IS_SOME>
+where
+	T: Deserialize<'de>,
+{
+	type Value = StaticOption<T, IS_SOME>;
+
+	fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+		if IS_SOME {
+			formatter.write_str("a `StaticOption::some`")
+		} else {
+			formatter.write_str("a `StaticOption::none`")
+		}
+	}
+
+	fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+		if IS_SOME {
+			Err(E::custom("expected a `StaticOption::some`, found no value"))
+		} else {
+			Ok(StaticOption::new_none())
+		}
+	}
+
+	fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+		self.visit_none()
+	}
+
+	fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+		if IS_SOME {
+			T::deserialize(deserializer).map(StaticOption::new_some)
+		} else {
+			Err(de::Error::custom("expected a `StaticOption::none`, found a value"))
+		}
+	}
+}
+
+const VARIANTS: &[&str] = &["Ok", "Err"];
+
+/// Uses the same representation as [`Result`], an enum with an `Ok` and an `Err` newtype variant.
+impl<T, E, const IS_OK: bool> Serialize for StaticResult<T, E, IS_OK>
+where
+	T: Serialize,
+	E: Serialize,
+{
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		match self.as_result() {
+			Ok(ok) => serializer.serialize_newtype_variant("StaticResult", 0, "Ok", ok),
+			Err(error) => serializer.serialize_newtype_variant("StaticResult", 1, "Err", error),
+		}
+	}
+}
+
+/// Deserializing fails if the variant doesn't match `IS_OK`.
+impl<'de, T, E, const IS_OK: bool> Deserialize<'de> for StaticResult<T, E, IS_OK>
+where
+	T: Deserialize<'de>,
+	E: Deserialize<'de>,
+{
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_enum("StaticResult", VARIANTS, ResultVisitor(PhantomData))
+	}
+}
+
+struct ResultVisitor<T, E, const IS_OK: bool>(PhantomData<(T, E)>);
+
+impl<'de, T, E, const IS_OK: bool> Visitor<'de> for ResultVisitor<T, E, IS_OK>
+where
+	T: Deserialize<'de>,
+	E: Deserialize<'de>,
+{
+	type Value = StaticResult<T, E, IS_OK>;
+
+	fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+		if IS_OK {
+			formatter.write_str("a `StaticResult::ok`")
+		} else {
+			formatter.write_str("a `StaticResult::err`")
+		}
+	}
+
+	fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+		let (variant, access) = data.variant::<Variant>()?;
+		match (variant, IS_OK) {
+			(Variant::Ok, true) => access.newtype_variant().map(StaticResult::create_ok),
+			(Variant::Err, false) => access.newtype_variant().map(StaticResult::create_err),
+			(Variant::Ok, false) => Err(de::Error::custom("expected a `StaticResult::err`, found an `ok` value")),
+			(Variant::Err, true) => Err(de::Error::custom("expected a `StaticResult::ok`, found an error")),
+		}
+	}
+}
+
+enum Variant {
+	Ok,
+	Err,
+}
+
+impl<'de> Deserialize<'de> for Variant {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_identifier(VariantVisitor)
+	}
+}
+
+struct VariantVisitor;
+
+impl<'de> Visitor<'de> for VariantVisitor {
+	type Value = Variant;
+
+	fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+		formatter.write_str("`Ok` or `Err`")
+	}
+
+	fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+		match value {
+			0 => Ok(Variant::Ok),
+			1 => Ok(Variant::Err),
+			_ => Err(E::invalid_value(Unexpected::Unsigned(value), &self)),
+		}
+	}
+
+	fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+		match value {
+			"Ok" => Ok(Variant::Ok),
+			"Err" => Ok(Variant::Err),
+			_ => Err(E::unknown_variant(value, VARIANTS)),
+		}
+	}
+
+	fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+		match value {
+			b"Ok" => Ok(Variant::Ok),
+			b"Err" => Ok(Variant::Err),
+			_ => Err(E::invalid_value(Unexpected::Bytes(value), &self)),
+		}
+	}
+}
@@ -0,0 +1,174 @@
+use crate::{StaticOption, StaticResult};
+use speedy::{Context, Readable, Reader, Writable, Writer};
+
+/// Uses the same representation as [`Option`], a `u8` presence flag followed by the value if present.
+///
+/// Reading fails with an error if the presence flag doesn't match `IS_SOME`.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticOption;
+/// use speedy::{Readable, Writable};
+///
+/// let bytes = StaticOption::some(42u32).write_to_vec().unwrap();
+/// assert_eq!(Some(42u32).write_to_vec().unwrap(), bytes);
+/// assert_eq!(StaticOption::some(42u32), StaticOption::read_from_buffer(&bytes).unwrap());
+///
+/// let bytes = StaticOption::<u32, false>::none().write_to_vec().unwrap();
+/// assert_eq!(None::<u32>.write_to_vec().unwrap(), bytes);
+/// assert_eq!(StaticOption::<u32, false>::none(), StaticOption::read_from_buffer(&bytes).unwrap());
+/// ```
+///
+/// ```
+/// # use static_option::StaticOption;
+/// use speedy::{Readable, Writable};
+///
+/// let bytes = StaticOption::some(42u32).write_to_vec().unwrap();
+/// assert!(StaticOption::<u32, false>::read_from_buffer(&bytes).is_err());
+///
+/// let bytes = StaticOption::<u32, false>::none().write_to_vec().unwrap();
+/// assert!(StaticOption::<u32, true>::read_from_buffer(&bytes).is_err());
+/// ```
+impl<'a, C, T, const IS_SOME: bool> Readable<'a, C> for StaticOption<T, IS_SOME>
+where
+	C: Context,
+	T: Readable<'a, C>,
+{
+	fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+		let is_some: bool = reader.read_value()?;
+		if is_some != IS_SOME {
+			return Err(presence_mismatch(is_some));
+		}
+
+		if IS_SOME {
+			Ok(StaticOption::new_some(reader.read_value()?))
+		} else {
+			Ok(StaticOption::new_none())
+		}
+	}
+
+	fn minimum_bytes_needed() -> usize {
+		if IS_SOME {
+			1 + T::minimum_bytes_needed()
+		} else {
+			1
+		}
+	}
+}
+
+impl<C, T, const IS_SOME: bool> Writable<C> for StaticOption<T, IS_SOME>
+where
+	C: Context,
+	T: Writable<C>,
+{
+	fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+		writer.write_u8(IS_SOME.into())?;
+		if IS_SOME {
+			self.as_inner().write_to(writer)?;
+		}
+		Ok(())
+	}
+
+	fn bytes_needed(&self) -> Result<usize, C::Error> {
+		if IS_SOME {
+			Ok(1 + Writable::<C>::bytes_needed(self.as_inner())?)
+		} else {
+			Ok(1)
+		}
+	}
+}
+
+/// Uses the same representation as [`Result`], a `u8` flag that is `1` for `ok` and `0` for `err`,
+/// followed by the `ok` value or the error respectively.
+///
+/// Reading fails with an error if the flag doesn't match `IS_OK`.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticResult;
+/// use speedy::{Readable, Writable};
+///
+/// let result = StaticResult::<u32, String, true>::new_ok(42);
+/// let bytes = result.write_to_vec().unwrap();
+/// assert_eq!(Ok::<u32, String>(42).write_to_vec().unwrap(), bytes);
+/// assert_eq!(result, StaticResult::read_from_buffer(&bytes).unwrap());
+///
+/// let result = StaticResult::<u32, String, false>::new_err("error".into());
+/// let bytes = result.write_to_vec().unwrap();
+/// assert_eq!(Err::<u32, String>("error".into()).write_to_vec().unwrap(), bytes);
+/// assert_eq!(result, StaticResult::read_from_buffer(&bytes).unwrap());
+/// ```
+///
+/// ```
+/// # use static_option::StaticResult;
+/// use speedy::{Readable, Writable};
+///
+/// let bytes = StaticResult::<u32, u32, true>::new_ok(42).write_to_vec().unwrap();
+/// assert!(StaticResult::<u32, u32, false>::read_from_buffer(&bytes).is_err());
+/// ```
+impl<'a, C, T, E, const IS_OK: bool> Readable<'a, C> for StaticResult<T, E, IS_OK>
+where
+	C: Context,
+	T: Readable<'a, C>,
+	E: Readable<'a, C>,
+{
+	fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+		let is_ok: bool = reader.read_value()?;
+		if is_ok != IS_OK {
+			return Err(polarity_mismatch(is_ok));
+		}
+
+		if IS_OK {
+			Ok(StaticResult::create_ok(reader.read_value()?))
+		} else {
+			Ok(StaticResult::create_err(reader.read_value()?))
+		}
+	}
+
+	fn minimum_bytes_needed() -> usize {
+		if IS_OK {
+			1 + T::minimum_bytes_needed()
+		} else {
+			1 + E::minimum_bytes_needed()
+		}
+	}
+}
+
+impl<C, T, E, const IS_OK: bool> Writable<C> for StaticResult<T, E, IS_OK>
+where
+	C: Context,
+	T: Writable<C>,
+	E: Writable<C>,
+{
+	fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+		writer.write_u8(IS_OK.into())?;
+		match self.as_result() {
+			Ok(ok) => ok.write_to(writer),
+			Err(error) => error.write_to(writer),
+		}
+	}
+
+	fn bytes_needed(&self) -> Result<usize, C::Error> {
+		let payload = match self.as_result() {
+			Ok(ok) => Writable::<C>::bytes_needed(ok)?,
+			Err(error) => Writable::<C>::bytes_needed(error)?,
+		};
+		Ok(1 + payload)
+	}
+}
+
+fn presence_mismatch<E: From<speedy::Error>>(is_some: bool) -> E {
+	if is_some {
+		speedy::Error::custom("expected a `StaticOption::none`, found a value").into()
+	} else {
+		speedy::Error::custom("expected a `StaticOption::some`, found no value").into()
+	}
+}
+
+fn polarity_mismatch<E: From<speedy::Error>>(is_ok: bool) -> E {
+	if is_ok {
+		speedy::Error::custom("expected a `StaticResult::err`, found an `ok` value").into()
+	} else {
+		speedy::Error::custom("expected a `StaticResult::ok`, found an error").into()
+	}
+}
@@ -0,0 +1,223 @@
+use crate::StaticOption;
+
+/// Tuples of [`StaticOption`]s that can be combined with [`all`].
+pub trait AllOptions {
+	/// A [`StaticOption`] of a tuple of all the values, which is only `some` if all options are `some`.
+	type Output;
+
+	/// See [`all`].
+	fn all(self) -> Self::Output;
+}
+
+/// Tuples of [`StaticOption`]s that can be combined with [`any`].
+pub trait AnyOptions {
+	/// A [`StaticOption`] of the first value, which is `some` if any option is `some`.
+	type Output;
+
+	/// See [`any`].
+	fn any(self) -> Self::Output;
+}
+
+/// Combine a tuple of [`StaticOption`]s into a [`StaticOption`] of a tuple, which is only `some` if all options
+/// are `some`. Otherwise all values are dropped.
+///
+/// This is like a `zip` for a fixed number of options, the presence of the result is computed at compile time.
+///
+/// # Examples
+/// ```
+/// # use static_option::{all, StaticOption};
+/// assert_eq!(StaticOption::some((1, "2")), all((StaticOption::some(1), StaticOption::some("2"))));
+/// assert_eq!(StaticOption::none(), all((StaticOption::some(1), StaticOption::<&str, false>::none())));
+/// assert_eq!(StaticOption::none(), all((StaticOption::<i32, false>::none(), StaticOption::some("2"))));
+/// assert_eq!(
+/// 	StaticOption::some((1, "2", 3.0)),
+/// 	all((StaticOption::some(1), StaticOption::some("2"), StaticOption::some(3.0)))
+/// );
+/// assert_eq!(
+/// 	StaticOption::none(),
+/// 	all((StaticOption::some(1), StaticOption::some("2"), StaticOption::<f64, false>::none()))
+/// );
+/// ```
+///
+/// ```
+/// # use static_option::{all, StaticOption};
+/// # use std::rc::Rc;
+/// let counter = Rc::new(());
+/// let options = (
+/// 	StaticOption::some(counter.clone()),
+/// 	StaticOption::<Rc<()>, false>::none(),
+/// 	StaticOption::some(counter.clone()),
+/// );
+/// assert_eq!(StaticOption::none(), all(options));
+/// assert_eq!(1, Rc::strong_count(&counter));
+/// ```
+pub fn all<Options>(options: Options) -> Options::Output
+where
+	Options: AllOptions,
+{
+	options.all()
+}
+
+/// Return the first of a tuple of [`StaticOption`]s that is `some`, dropping all others. If none of them is
+/// `some`, return [`StaticOption::none`].
+///
+/// This is like chaining `or` for a fixed number of options, the presence of the result is computed at compile time.
+///
+/// # Examples
+/// ```
+/// # use static_option::{any, StaticOption};
+/// assert_eq!(StaticOption::some(1), any((StaticOption::some(1), StaticOption::some(2))));
+/// assert_eq!(StaticOption::some(2), any((StaticOption::none(), StaticOption::some(2))));
+/// assert_eq!(StaticOption::<i32, false>::none(), any((StaticOption::none(), StaticOption::none())));
+/// assert_eq!(
+/// 	StaticOption::some(3),
+/// 	any((StaticOption::none(), StaticOption::none(), StaticOption::some(3)))
+/// );
+/// assert_eq!(
+/// 	StaticOption::<i32, false>::none(),
+/// 	any((StaticOption::none(), StaticOption::none(), StaticOption::none()))
+/// );
+/// ```
+///
+/// ```
+/// # use static_option::{any, StaticOption};
+/// # use std::rc::Rc;
+/// let counter = Rc::new(());
+/// let options = (
+/// 	StaticOption::none(),
+/// 	StaticOption::some(counter.clone()),
+/// 	StaticOption::some(counter.clone()),
+/// );
+/// let option = any(options);
+/// assert_eq!(2, Rc::strong_count(&counter));
+/// option.drop();
+/// assert_eq!(1, Rc::strong_count(&counter));
+/// ```
+pub fn any<Options>(options: Options) -> Options::Output
+where
+	Options: AnyOptions,
+{
+	options.any()
+}
+
+impl<A, B> AllOptions for (StaticOption<A, true>, StaticOption<B, true>) {
+	type Output = StaticOption<(A, B), true>;
+
+	fn all(self) -> Self::Output {
+		let (a, b) = self;
+		StaticOption::some((a.into_inner(), b.into_inner()))
+	}
+}
+
+impl<A, B> AllOptions for (StaticOption<A, true>, StaticOption<B, false>) {
+	type Output = StaticOption<(A, B), false>;
+
+	fn all(self) -> Self::Output {
+		let (a, _) = self;
+		a.drop();
+		StaticOption::none()
+	}
+}
+
+impl<A, B, const B_SOME: bool> AllOptions for (StaticOption<A, false>, StaticOption<B, B_SOME>) {
+	type Output = StaticOption<(A, B), false>;
+
+	fn all(self) -> Self::Output {
+		let (_, b) = self;
+		b.drop();
+		StaticOption::none()
+	}
+}
+
+impl<A, B, C> AllOptions for (StaticOption<A, true>, StaticOption<B, true>, StaticOption<C, true>) {
+	type Output = StaticOption<(A, B, C), true>;
+
+	fn all(self) -> Self::Output {
+		let (a, b, c) = self;
+		StaticOption::some((a.into_inner(), b.into_inner(), c.into_inner()))
+	}
+}
+
+impl<A, B, C> AllOptions for (StaticOption<A, true>, StaticOption<B, true>, StaticOption<C, false>) {
+	type Output = StaticOption<(A, B, C), false>;
+
+	fn all(self) -> Self::Output {
+		let (a, b, _) = self;
+		a.drop();
+		b.drop();
+		StaticOption::none()
+	}
+}
+
+impl<A, B, C, const C_SOME: bool> AllOptions
+	for (StaticOption<A, true>, StaticOption<B, false>, StaticOption<C, C_SOME>)
+{
+	type Output = StaticOption<(A, B, C), false>;
+
+	fn all(self) -> Self::Output {
+		let (a, _, c) = self;
+		a.drop();
+		c.drop();
+		StaticOption::none()
+	}
+}
+
+impl<A, B, C, const B_SOME: bool, const C_SOME: bool> AllOptions
+	for (StaticOption<A, false>, StaticOption<B, B_SOME>, StaticOption<C, C_SOME>)
+{
+	type Output = StaticOption<(A, B, C), false>;
+
+	fn all(self) -> Self::Output {
+		let (_, b, c) = self;
+		b.drop();
+		c.drop();
+		StaticOption::none()
+	}
+}
+
+impl<T, const B_SOME: bool> AnyOptions for (StaticOption<T, true>, StaticOption<T, B_SOME>) {
+	type Output = StaticOption<T, true>;
+
+	fn any(self) -> Self::Output {
+		let (a, b) = self;
+		a.or(b)
+	}
+}
+
+impl<T, const B_SOME: bool> AnyOptions for (StaticOption<T, false>, StaticOption<T, B_SOME>) {
+	type Output = StaticOption<T, B_SOME>;
+
+	fn any(self) -> Self::Output {
+		let (a, b) = self;
+		a.or(b)
+	}
+}
+
+impl<T, const B_SOME: bool, const C_SOME: bool> AnyOptions
+	for (StaticOption<T, true>, StaticOption<T, B_SOME>, StaticOption<T, C_SOME>)
+{
+	type Output = StaticOption<T, true>;
+
+	fn any(self) -> Self::Output {
+		let (a, b, c) = self;
+		a.or(b).or(c)
+	}
+}
+
+impl<T, const C_SOME: bool> AnyOptions for (StaticOption<T, false>, StaticOption<T, true>, StaticOption<T, C_SOME>) {
+	type Output = StaticOption<T, true>;
+
+	fn any(self) -> Self::Output {
+		let (a, b, c) = self;
+		a.or(b).or(c)
+	}
+}
+
+impl<T, const C_SOME: bool> AnyOptions for (StaticOption<T, false>, StaticOption<T, false>, StaticOption<T, C_SOME>) {
+	type Output = StaticOption<T, C_SOME>;
+
+	fn any(self) -> Self::Output {
+		let (a, b, c) = self;
+		a.or(b).or(c)
+	}
+}
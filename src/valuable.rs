@@ -0,0 +1,91 @@
+use crate::{StaticOption, StaticResult};
+use valuable::{EnumDef, Enumerable, Fields, Valuable, Value, Variant, VariantDef, Visit};
+
+/// Uses the same representation as [`Option`], the value itself if present and [`Value::Unit`] otherwise.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticOption;
+/// use valuable::{Valuable, Value};
+///
+/// assert!(matches!(StaticOption::some(42u32).as_value(), Value::U32(42)));
+/// assert!(matches!(StaticOption::<u32, false>::none().as_value(), Value::Unit));
+/// ```
+impl<T, const IS_SOME: bool> Valuable for StaticOption<T, IS_SOME>
+where
+	T: Valuable,
+{
+	fn as_value(&self) -> Value<'_> {
+		match self.as_option() {
+			Some(value) => value.as_value(),
+			None => Value::Unit,
+		}
+	}
+
+	fn visit(&self, visit: &mut dyn Visit) {
+		visit.visit_value(self.as_value());
+	}
+}
+
+static VARIANTS: &[VariantDef<'static>] = &[
+	VariantDef::new("Ok", Fields::Unnamed(1)),
+	VariantDef::new("Err", Fields::Unnamed(1)),
+];
+
+/// Uses the same representation as [`Result`], an enum with an `Ok` and an `Err` variant that contain one unnamed
+/// field each.
+///
+/// # Examples
+/// ```
+/// # use static_option::StaticResult;
+/// use valuable::{Valuable, Value};
+///
+/// let result = StaticResult::<u32, &'static str, true>::new_ok(42);
+/// match result.as_value() {
+/// 	Value::Enumerable(enumerable) => {
+/// 		assert_eq!("StaticResult", enumerable.definition().name());
+/// 		assert_eq!("Ok", enumerable.variant().name());
+/// 	}
+/// 	_ => panic!("not an enum"),
+/// }
+///
+/// let result = StaticResult::<u32, &'static str, false>::new_err("error");
+/// match result.as_value() {
+/// 	Value::Enumerable(enumerable) => assert_eq!("Err", enumerable.variant().name()),
+/// 	_ => panic!("not an enum"),
+/// }
+/// ```
+impl<T, E, const IS_OK: bool> Enumerable for StaticResult<T, E, IS_OK>
+where
+	T: Valuable,
+	E: Valuable,
+{
+	fn definition(&self) -> EnumDef<'_> {
+		EnumDef::new_static("StaticResult", VARIANTS)
+	}
+
+	fn variant(&self) -> Variant<'_> {
+		if IS_OK {
+			Variant::Static(&VARIANTS[0])
+		} else {
+			Variant::Static(&VARIANTS[1])
+		}
+	}
+}
+
+impl<T, E, const IS_OK: bool> Valuable for StaticResult<T, E, IS_OK>
+where
+	T: Valuable,
+	E: Valuable,
+{
+	fn as_value(&self) -> Value<'_> {
+		Value::Enumerable(self)
+	}
+
+	fn visit(&self, visitor: &mut dyn Visit) {
+		match self.as_result() {
+			Ok(ok) => visitor.visit_unnamed_fields(&[ok.as_value()]),
+			Err(error) => visitor.visit_unnamed_fields(&[error.as_value()]),
+		}
+	}
+}
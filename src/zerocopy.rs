@@ -0,0 +1,47 @@
+//! `FromBytes`, `Immutable` and `KnownLayout` are derived on [`StaticOption`] directly, so a
+//! [`StaticOption<T, true>`] can be read from or borrowed in a byte buffer like `T`.
+//!
+//! # Examples
+//! ```
+//! # use static_option::StaticOption;
+//! use zerocopy::FromBytes;
+//!
+//! let bytes: &[u8] = &42u32.to_ne_bytes();
+//! let option = StaticOption::<u32, true>::read_from_bytes(bytes).unwrap();
+//! assert_eq!(StaticOption::some(42), option);
+//!
+//! let borrowed = StaticOption::<u32, true>::ref_from_bytes(bytes).unwrap();
+//! assert_eq!(42, *borrowed.inner_ref());
+//!
+//! assert!(StaticOption::<u32, true>::read_from_bytes(&bytes[..3]).is_err());
+//! assert!(StaticOption::<u32, true>::ref_from_bytes(&[0u8; 8]).is_err());
+//! ```
+use crate::StaticOption;
+use zerocopy::{Immutable, IntoBytes};
+
+// zerocopy only allows implementing `IntoBytes` with its derive, which can't be restricted to `StaticOption<T, true>`
+// and would also cover the uninitialized bytes of a `StaticOption<T, false>`. So `as_some_bytes` is provided instead.
+impl<T> StaticOption<T, true>
+where
+	T: IntoBytes + Immutable,
+{
+	/// View the value inside a [`StaticOption<T, true>`] as bytes. Since [`StaticOption<T, true>`] has the same
+	/// layout as `T`, these are exactly the bytes of the [`StaticOption`] itself.
+	///
+	/// Note that a [`StaticOption<T, false>`] has the same size as `T` as well, not zero.
+	///
+	/// # Example
+	/// ```
+	/// # use static_option::StaticOption;
+	/// use zerocopy::{FromBytes, IntoBytes};
+	///
+	/// let value = 42u32;
+	/// let bytes: &[u8] = value.as_bytes();
+	/// let option = StaticOption::<u32, true>::read_from_bytes(bytes).unwrap();
+	/// assert_eq!(bytes, option.as_some_bytes());
+	/// assert_eq!(bytes, StaticOption::some(42u32).as_some_bytes());
+	/// ```
+	pub fn as_some_bytes(&self) -> &[u8] {
+		self.inner_ref().as_bytes()
+	}
+}